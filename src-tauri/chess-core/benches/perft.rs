@@ -0,0 +1,31 @@
+//! measures how fast positions can be generated and played with perft, run with `cargo bench -p chess-core`
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use chess_core::board::state::{ChessGameState, GameStateParser};
+use chess_core::moves::move_generator::MoveGenerator;
+use chess_core::moves::perft::perft;
+
+/// (name, fen, depth) of the positions to run perft on
+const POSITIONS: [(&str, &str, u32); 3] = [
+    ("start position", "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", 4),
+    ("kiwipete", "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1", 3),
+    ("position 3", "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4),
+];
+
+fn bench_perft(c: &mut Criterion) {
+    let mut move_generator = MoveGenerator { ..Default::default() };
+    let mut group = c.benchmark_group("perft");
+
+    for (name, fen, depth) in POSITIONS {
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen(fen).unwrap();
+
+        group.bench_function(name, |b| b.iter(|| perft(&mut move_generator, &mut state, black_box(depth))));
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_perft);
+criterion_main!(benches);
@@ -0,0 +1,116 @@
+//! searches the magic numbers for the bishop and rook lookup tables and prints them as rust constants, which can be
+//! pasted in the constants of board/bitboard.rs.
+//! <br>run with `cargo run -p chess-core --release --bin generate_magics -- [--seed <u32>] [--fancy] [--out <file>]`
+//! <br><br>--seed: the search always finds the same magic numbers for the same seed
+//! <br>--fancy: also searches for magic numbers that use less bits than the mask has, the relevant bits need to be
+//! pasted with them. MagicMoves sizes the table of every square by its relevant bits, so this makes the tables smaller
+//! <br>--out: writes the constants to the file instead of printing them
+
+use std::env;
+use std::fs;
+
+use chess_core::board::types::Square;
+use chess_core::helpers::random::{DEFAULT_SEED, XorShiftRng};
+use chess_core::moves::magic_moves::{MagicMoves, MagicMovesGenerator};
+use chess_core::pieces::piece_interfaces::SlidingPiece;
+
+struct Options {
+    seed: u32,
+    fancy: bool,
+    out: Option<String>,
+}
+
+fn parse_options() -> Options {
+    let mut options = Options { seed: DEFAULT_SEED, fancy: false, out: None };
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                options.seed = args.next().and_then(|seed| seed.parse().ok()).filter(|seed| *seed != 0)
+                    .expect("--seed needs a number above 0");
+            }
+            "--fancy" => options.fancy = true,
+            "--out" => options.out = Some(args.next().expect("--out needs a file")),
+            _ => panic!("unknown argument '{}'", arg),
+        }
+    }
+
+    return options;
+}
+
+/// finds a verified magic number for every square, returns (magic numbers, bits used for the index)
+fn search_magics(
+    magic_moves: &mut MagicMoves, gen_bishop: bool, fancy: bool, rng: &mut XorShiftRng,
+) -> (Vec<u64>, Vec<i32>) {
+    let mut magics: Vec<u64> = vec![0u64; 64];
+    let mut relevant_bits: Vec<i32> = vec![0; 64];
+
+    for sq in Square::all() {
+        let mask: u64 = if gen_bishop {
+            magic_moves.bishop_generator.get_mask(sq)
+        } else {
+            magic_moves.rook_generator.get_mask(sq)
+        };
+        let mut bits: i32 = mask.count_ones() as i32;
+        let mut magic: u64 = magic_moves.generate_magic_num(sq, bits, gen_bishop, rng);
+
+        assert_ne!(magic, 0u64, "no magic number found for square {}", sq);
+
+        // keep using one bit less until no magic number can be found anymore
+        while fancy && bits > 1 {
+            let smaller_magic: u64 = magic_moves.generate_magic_num(sq, bits - 1, gen_bishop, rng);
+
+            if smaller_magic == 0u64 {
+                break;
+            }
+            magic = smaller_magic;
+            bits -= 1;
+        }
+
+        assert!(magic_moves.verify_magic_num(sq, magic, bits, gen_bishop), "magic number of square {} is wrong", sq);
+        eprintln!("{} {}: {} ({} bits)", if gen_bishop { "bishop" } else { "rook" }, sq, magic, bits);
+
+        magics[sq.index()] = magic;
+        relevant_bits[sq.index()] = bits;
+    }
+
+    return (magics, relevant_bits);
+}
+
+/// formats the relevant bits as a 8x8 board and the magic numbers with one per line, like in board/bitboard.rs
+fn to_constants(piece: &str, magics: &[u64], relevant_bits: &[i32]) -> String {
+    let ranks: Vec<String> = relevant_bits.chunks(8)
+        .map(|rank| format!("        {}", rank.iter().map(|bits| bits.to_string()).collect::<Vec<String>>().join(", ")))
+        .collect();
+    let magic_lines: String = magics.iter().map(|magic| format!("        {}u64,\n", magic)).collect();
+
+    return format!(
+        "    pub const {piece}_RELEVANT_BITS: [u64; 64] = [\n{}\n    ];\n\n    pub(crate) const {piece}_MAGIC: [u64; 64] = [\n{}    ];\n",
+        ranks.join(",\n"),
+        magic_lines,
+        piece = piece,
+    );
+}
+
+fn main() {
+    let options: Options = parse_options();
+    let mut magic_moves = MagicMoves { ..Default::default() };
+    let mut rng = XorShiftRng::new(options.seed);
+
+    let (bishop_magics, bishop_bits) = search_magics(&mut magic_moves, true, options.fancy, &mut rng);
+    let (rook_magics, rook_bits) = search_magics(&mut magic_moves, false, options.fancy, &mut rng);
+
+    let constants: String = format!(
+        "    // generated by generate_magics with seed {}{}\n{}\n{}",
+        options.seed,
+        if options.fancy { " --fancy" } else { "" },
+        to_constants("BISHOP", &bishop_magics, &bishop_bits),
+        to_constants("ROOK", &rook_magics, &rook_bits),
+    );
+
+    match options.out {
+        Some(file) => fs::write(&file, constants).expect("couldn't write the constants"),
+        None => print!("{}", constants),
+    }
+}
@@ -1,5 +1,6 @@
 pub mod math {
     use crate::board::bitboard::constants::{DEBRUIJ_T, DEBRUIJ_M};
+    use crate::board::types::Square;
 
     /// sets the bit on a bitboard for example:
     /// bb=0000, sq=1 => 0100(sq starts by 0)
@@ -59,12 +60,12 @@ pub mod math {
        };
     }
 
-    ///sets the bit on a square if it doesn't exists on the mask
-    pub(crate) fn set_bit_not_exists(mut bb: u64, mask: u64, sq: i32) -> u64 {
-        if sq < 64 && !get_bit!(mask, sq) {
-            set_bit!(&mut bb, sq);
-
-            return bb;
+    ///sets the bit on a square if it doesn't exists on the mask, nothing is set if the square is off the board(None)
+    pub(crate) fn set_bit_not_exists(mut bb: u64, mask: u64, sq: Option<Square>) -> u64 {
+        if let Some(sq) = sq {
+            if mask & sq.bit() == 0u64 {
+                bb |= sq.bit();
+            }
         }
 
         return bb;
@@ -124,6 +125,11 @@ pub mod constants {
     pub const AB_FILE: u64 = 217020518514230019u64;
     pub const GH_FILE: u64 = 13889313184910721216u64;
     pub const H_FILE: u64 = 9259542123273814144u64;
+    pub const RANK_8: u64 = 255u64;
+    pub const RANK_7: u64 = 65280u64;
+    pub const RANK_2: u64 = 71776119061217280u64;
+    pub const RANK_1: u64 = 18374686479671623680u64;
+    pub const LIGHT_SQUARES: u64 = 12273903644374837845u64;
 
     pub const BISHOP_RELEVANT_BITS: [u64; 64] = [
         6, 5, 5, 5, 5, 5, 5, 6,
@@ -162,137 +168,139 @@ pub mod constants {
     pub(crate) const DEBRUIJ_M: u64 = 0x03f7_9d71_b4cb_0a89;
 
     // we need to store the magic we have generated so that we can ensure the move generation will go as planned
+    // generated by generate_magics with seed 892777658
     pub(crate) const BISHOP_MAGIC: [u64; 64] = [
-        1206973522010850048u64,
-        74335820789710848u64,
-        1166436742339887121u64,
-        1143775561253184u64,
-        5765172741032387096u64,
-        294807428697472u64,
-        86694851308945408u64,
-        5102850680031752u64,
-        118764505138196u64,
-        2306144344723718272u64,
-        576478637629513746u64,
-        3544879191820290u64,
-        4553203255816u64,
-        9007818003194176u64,
-        1251253096155138u64,
-        292066431040u64,
-        2382404838606504976u64,
-        578712569314279936u64,
-        576884133016387841u64,
-        4631952253394157648u64,
-        5635686445096960u64,
-        11529355801005361216u64,
-        11538785197435656384u64,
-        1225542049303241216u64,
-        1164831449997640200u64,
-        27619732358430864u64,
-        9241959830812885056u64,
-        5767000198345785856u64,
-        281543780089888u64,
-        29557071582220290u64,
-        9223550710212723851u64,
-        5139426619556352u64,
-        4512501568196608u64,
-        2306975540579602432u64,
-        595337460156205056u64,
-        9289088698417280u64,
-        18050682661699904u64,
-        2938616350119069696u64,
-        4509441882456578u64,
-        1748228991222088u64,
-        146371454749902848u64,
-        4612833915344783360u64,
-        2450522263951313411u64,
-        7205900279072430080u64,
-        659786145810875393u64,
-        706505217147136u64,
-        218724782970049040u64,
-        468669103389803008u64,
-        9226752013713539076u64,
-        145805490072339456u64,
-        360979369988u64,
-        675549022064741377u64,
-        9403517310985175041u64,
-        317896333103104u64,
-        13669335268614148u64,
-        579293386323558784u64,
-        6900544656646145u64,
-        4611704711367623936u64,
-        9368051025455416320u64,
-        206913701889u64,
-        3458785748675994624u64,
-        74309413447540224u64,
-        10664634008496898176u64,
-        37163596639732224u64,];
+        10538431928502329412u64,
+        81067318750683140u64,
+        1193472594074927113u64,
+        1197095436943360u64,
+        1190079534704132098u64,
+        7604359965704208u64,
+        37308699020361792u64,
+        10383068199603552400u64,
+        2308104713828567552u64,
+        1460168630501888u64,
+        2342311753192636674u64,
+        10376315548916089984u64,
+        2594095393315770372u64,
+        6343329429871132936u64,
+        9007757869326336u64,
+        594475438585171968u64,
+        585468054689024000u64,
+        9248150640832807043u64,
+        580965073508704292u64,
+        74309404756819972u64,
+        721949711927296u64,
+        14772089360872441088u64,
+        9224005357734465536u64,
+        4611761000000127248u64,
+        2351046174482370818u64,
+        578752138864494592u64,
+        1155322846861377554u64,
+        3014047244891654160u64,
+        13835211989065965568u64,
+        18367341776601601u64,
+        577663079073055505u64,
+        286972537112640u64,
+        1303123726766180352u64,
+        615872884174505088u64,
+        2319592694261221377u64,
+        70677998633472u64,
+        2306688550843842976u64,
+        38291609268031488u64,
+        2255116870682624u64,
+        9259542671949693472u64,
+        10811744161961608192u64,
+        13583444227530816u64,
+        9241672311105391617u64,
+        11822023244317696u64,
+        2330969017156608u64,
+        2456748815464530448u64,
+        4508294094000192u64,
+        18650208305090848u64,
+        577587210825500934u64,
+        1517856047711388160u64,
+        1101802782768u64,
+        63054836325155328u64,
+        288274402004174848u64,
+        1189240710269272064u64,
+        153160389301928960u64,
+        21745058709537864u64,
+        9228724768763814400u64,
+        149834238543876u64,
+        1152923721951085568u64,
+        4504149392884736u64,
+        4620977176232600064u64,
+        11530347613409805056u64,
+        2305847544833507456u64,
+        1152296796946945u64,
+    ];
 
     pub(crate) const ROOK_MAGIC: [u64; 64] = [
-        4647723886419382913u64,
-        234187318132473856u64,
-        3819087668652294150u64,
-        36081573653118980u64,
-        1188954699974836357u64,
-        108096295264321536u64,
-        36029896539111426u64,
-        144115812058276896u64,
-        282024736718849u64,
-        27162610132582400u64,
-        4721461398932080640u64,
-        703756161777674u64,
-        9288777311726608u64,
-        562965590315008u64,
-        5629645631260672u64,
-        874261294849593472u64,
-        9223425363172917328u64,
-        76562018301116546u64,
-        4611705810177785859u64,
-        1153520738579251208u64,
-        151732807016704u64,
-        2305845208304586784u64,
-        9020394216620672u64,
-        2305843558973767688u64,
-        5188217141623545856u64,
-        9223688698355254274u64,
-        2386907976453586948u64,
-        2306458737873256452u64,
-        4521453809041408u64,
-        9150137918291970u64,
-        116153096089601u64,
-        882712128329388640u64,
-        9290050802034688u64,
-        576636742887538960u64,
-        292742789054070788u64,
-        2919608060760564224u64,
-        35562631201808u64,
-        1134765826638976u64,
-        2199040033796u64,
-        144259241312518208u64,
-        7062947416177246212u64,
-        4756293867706286080u64,
-        45036271959080968u64,
-        211141433360392u64,
-        73755252943257600u64,
-        5193798261604909064u64,
-        1224981314933096458u64,
-        2467973712499048464u64,
-        289393660645245952u64,
-        4611826893358891264u64,
-        4611703611714437376u64,
-        17592320303360u64,
-        70442296017920u64,
-        4398214348928u64,
-        18016610686612480u64,
-        4613938419540754944u64,
-        140754940854305u64,
-        35186523769395u64,
-        576742302446280716u64,
-        4683752684578144290u64,
-        1734325804064047106u64,
-        865254233027906049u64,
-        9302190581817018372u64,
-        2308376292598251633u64,
+        36029071900156033u64,
+        36051337544351744u64,
+        36081574114003072u64,
+        4755805879971402240u64,
+        144119622797369424u64,
+        72063091630932104u64,
+        144117387386945668u64,
+        144118488760468482u64,
+        9836002462178246656u64,
+        1191835425507905544u64,
+        4615345270970974400u64,
+        577164474113372160u64,
+        144396697413403904u64,
+        703696031973504u64,
+        281621005664260u64,
+        9440389334093545858u64,
+        603082266247553u64,
+        9007750084395109u64,
+        9538624560794993186u64,
+        1152958888811823112u64,
+        4615064268574851076u64,
+        11547370731922785280u64,
+        9252681722611630592u64,
+        2311580260904288420u64,
+        4613974654028136450u64,
+        2310351282839232512u64,
+        2310347177925353472u64,
+        9043487438668040u64,
+        4617042841233785856u64,
+        562992904149044u64,
+        8950712894722u64,
+        4574629796529153u64,
+        422213589147904u64,
+        431283645718529u64,
+        1157724173619961858u64,
+        2269396311482632u64,
+        652010747594752u64,
+        1736700675091595296u64,
+        578941804653586438u64,
+        9009969542201412u64,
+        3459187453208985600u64,
+        144256617087631424u64,
+        5911285359378448u64,
+        13844153215601377408u64,
+        10133683285557316u64,
+        2382967206790692920u64,
+        14521294495515017217u64,
+        297805477528862732u64,
+        580834751185408u64,
+        4573973203419520u64,
+        9232379373816939136u64,
+        2767462177185999232u64,
+        576474393120669952u64,
+        6989727402122543488u64,
+        4900057157837324416u64,
+        1971434306142720u64,
+        5772436350238914u64,
+        5261048860733621026u64,
+        19175483912554513u64,
+        37436739376119817u64,
+        887490885511546881u64,
+        689613834742697986u64,
+        6940706810073448708u64,
+        9007766731555842u64,
     ];
 }
 
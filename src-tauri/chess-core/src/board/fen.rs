@@ -0,0 +1,75 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// everything that can be wrong with a fen string, so the user knows why the position can't be loaded
+/// https://nl.wikipedia.org/wiki/Forsyth-Edwards_Notation
+#[derive(Debug, PartialEq, Serialize)]
+pub enum FenError {
+    /// the fen doesn't contain the named field
+    MissingField(&'static str),
+    /// more than 6 fields were given
+    TooManyFields,
+    /// the piece placement doesn't contain exactly 8 ranks
+    InvalidRankCount(usize),
+    /// a rank(1-8) doesn't add up to 8 squares
+    InvalidRankLength { rank: u32, squares: u32 },
+    /// unknown character in the piece placement
+    InvalidPiece(char),
+    /// a side has no king on the board
+    MissingKing { white: bool },
+    /// a side has more than one king on the board
+    TooManyKings { white: bool },
+    /// a side has more than 8 pawns
+    TooManyPawns { white: bool },
+    /// a side has more pieces above the amount it starts with than pawns it's missing, they can't all be promoted
+    TooManyPromotedPieces { white: bool },
+    /// a pawn is standing on the first or last rank
+    PawnOnBackRank(String),
+    /// side to move isn't `w` or `b`
+    InvalidSideToMove(String),
+    /// castle rights contain something else than `KQkq` or `-`
+    InvalidCastleRights(String),
+    /// a castle right is given while the king or the rook isn't on its starting square
+    ImpossibleCastleRight(char),
+    /// en passant square isn't `-` or a square on the 3rd/6th rank behind the pawn that just moved
+    InvalidEnPassant(String),
+    /// halfmove clock isn't a positive number
+    InvalidHalfmoveClock(String),
+    /// fullmove number isn't a number above 0
+    InvalidFullmoveNumber(String),
+    /// the side that isn't to move is in check, so its king could be captured
+    SideNotToMoveInCheck,
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |white: &bool| if *white { "white" } else { "black" };
+
+        return match self {
+            FenError::MissingField(field) => write!(f, "fen is missing the {} field", field),
+            FenError::TooManyFields => write!(f, "fen has more than 6 fields"),
+            FenError::InvalidRankCount(count) => write!(f, "fen has {} ranks instead of 8", count),
+            FenError::InvalidRankLength { rank, squares } =>
+                write!(f, "rank {} has {} squares instead of 8", rank, squares),
+            FenError::InvalidPiece(piece) => write!(f, "'{}' is not a valid piece", piece),
+            FenError::MissingKing { white } => write!(f, "{} has no king", side(white)),
+            FenError::TooManyKings { white } => write!(f, "{} has more than one king", side(white)),
+            FenError::TooManyPawns { white } => write!(f, "{} has more than 8 pawns", side(white)),
+            FenError::TooManyPromotedPieces { white } =>
+                write!(f, "{} has more promoted pieces than pawns that could have promoted", side(white)),
+            FenError::PawnOnBackRank(sq) => write!(f, "pawn on {} can't be on the first or last rank", sq),
+            FenError::InvalidSideToMove(field) => write!(f, "'{}' is not a valid side to move", field),
+            FenError::InvalidCastleRights(field) => write!(f, "'{}' are not valid castle rights", field),
+            FenError::ImpossibleCastleRight(right) => {
+                write!(f, "castle right '{}' is given but the king or rook isn't on its starting square", right)
+            }
+            FenError::InvalidEnPassant(field) => write!(f, "'{}' is not a valid en passant square", field),
+            FenError::InvalidHalfmoveClock(field) => write!(f, "'{}' is not a valid halfmove clock", field),
+            FenError::InvalidFullmoveNumber(field) => write!(f, "'{}' is not a valid fullmove number", field),
+            FenError::SideNotToMoveInCheck => write!(f, "the side that is not to move is in check"),
+        };
+    }
+}
+
+impl std::error::Error for FenError {}
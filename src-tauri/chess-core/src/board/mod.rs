@@ -0,0 +1,5 @@
+pub mod bitboard;
+pub mod fen;
+pub mod state;
+pub mod types;
+pub mod zobrist;
\ No newline at end of file
@@ -0,0 +1,380 @@
+use crate::board::bitboard::constants::{RANK_1, RANK_8};
+use crate::board::fen::FenError;
+use crate::board::types::{Color, Piece, PieceKind, Square};
+use crate::board::zobrist::hash_position;
+use crate::moves::move_interfaces::Move;
+
+// castle rights are stored as bits in a single u8 so they can be saved/restored cheaply
+pub const WHITE_KING_SIDE: u8 = 1;
+pub const WHITE_QUEEN_SIDE: u8 = 2;
+pub const BLACK_KING_SIDE: u8 = 4;
+pub const BLACK_QUEEN_SIDE: u8 = 8;
+
+/// everything that is needed to take back a made move, which can't be worked out from the position after the move
+/// <br><br>captured_piece: piece the move has captured(None if none or if the move captured en passant)
+/// <br><br>hash: hash of the position before the move, together they are the history of positions of the game
+#[derive(Clone, Copy)]
+pub struct SaveState {
+    pub chess_move: Move,
+    pub captured_piece: Option<Piece>,
+    pub castle_rights: u8,
+    pub en_passant_sq: Option<Square>,
+    pub halfmove_clock: u32,
+    pub hash: u64,
+}
+
+/// bb: list of 12 u64's of positions of pieces(every piece has its own u64 so we can identify which piece is which)
+/// <br><br>occ: list of 3 u64's 1st is whites occ, 2nd is blacks occ, 3rd is whites and blacks occ together
+/// <br><br>side_to_move: dictates which side is allowed to move
+/// <br><br>castle_rights: bits of WHITE_KING_SIDE, WHITE_QUEEN_SIDE, BLACK_KING_SIDE and BLACK_QUEEN_SIDE
+/// <br><br>en_passant_sq: square a pawn skipped over with a double push last move(None if there is none)
+/// <br><br>halfmove_clock: moves since the last capture or pawn move
+/// <br><br>fullmove_number: starts at 1 and goes up after every move of black
+/// <br><br>hash: zobrist hash of the position, positions with the same pieces, side to move, castle rights and
+/// en passant square have the same hash
+/// <br><br>saved_states: one SaveState for every move that has been made, so the moves can be taken back
+#[derive(Clone)]
+pub struct ChessGameState {
+    pub bb: [u64; 12],
+    pub occ: [u64; 3],
+    pub side_to_move: Color,
+    pub castle_rights: u8,
+    pub en_passant_sq: Option<Square>,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+    pub hash: u64,
+    pub saved_states: Vec<SaveState>,
+}
+
+pub trait GameStateParser {
+    fn get_capture_occ_idx(&mut self) -> i32;
+    fn parse_fen(&mut self, fen: &str) -> Result<(), FenError>;
+    fn to_fen(&self) -> String;
+    fn update_occ(&mut self);
+    fn get_occ_idx(&mut self) -> i32;
+}
+
+impl Default for ChessGameState {
+    fn default() -> Self {
+        return Self {
+            bb: [0u64; 12],
+            occ: [0u64; 3],
+            side_to_move: Color::White,
+            castle_rights: 0,
+            en_passant_sq: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            hash: 0u64,
+            saved_states: vec![]
+        };
+    }
+}
+
+impl ChessGameState {
+    /// bitboard of the piece
+    #[inline(always)]
+    pub fn pieces(&self, piece: Piece) -> u64 {
+        return self.bb[piece.index()];
+    }
+
+    /// bitboard of the pieces of the kind of both sides
+    pub fn pieces_of_kind(&self, kind: PieceKind) -> u64 {
+        return self.bb[kind.index()] | self.bb[kind.index() + 6];
+    }
+
+    /// returns the piece that is standing on the square, None if the square is empty
+    pub fn piece_on(&self, sq: Square) -> Option<Piece> {
+        return Piece::all().find(|piece| self.pieces(*piece) & sq.bit() != 0u64);
+    }
+
+    /// returns the piece of the side that is standing on the square, None if there isn't one
+    #[inline(always)]
+    pub fn color_piece_on(&self, color: Color, sq: Square) -> Option<Piece> {
+        if self.occ[color.index()] & sq.bit() == 0u64 {
+            return None;
+        }
+
+        return PieceKind::ALL.into_iter()
+            .map(|kind| Piece::new(color, kind))
+            .find(|piece| self.pieces(*piece) & sq.bit() != 0u64);
+    }
+
+    /// square of the king of the side, there is always exactly one king per side on the board
+    pub fn king_square(&self, color: Color) -> Square {
+        return Square::from_ls1b(self.pieces(Piece::new(color, PieceKind::King)));
+    }
+
+    /// how many times the current position has been on the board since the position was loaded(including now).
+    /// a capture or pawn move can't be undone, so only the positions since the last one of those are checked
+    pub fn repetitions(&self) -> u32 {
+        // the positions with the same side to move are every other saved state, starting 2 moves ago
+        let earlier_positions = self.saved_states.iter()
+            .rev()
+            .take(self.halfmove_clock as usize)
+            .skip(1)
+            .step_by(2);
+
+        return 1 + earlier_positions.filter(|saved_state| saved_state.hash == self.hash).count() as u32;
+    }
+}
+
+impl GameStateParser for ChessGameState {
+    /// returns which side needs to be check if there is a piece
+    fn get_capture_occ_idx(&mut self) -> i32 {
+        return (!self.side_to_move).index() as i32;
+    }
+    /// parses fen based on(Forsyth-Edwards Notation), the halfmove clock and fullmove number may be left out.
+    /// the current position is only replaced if the whole fen is valid
+    /// https://nl.wikipedia.org/wiki/Forsyth-Edwards_Notation
+    fn parse_fen(&mut self, fen: &str) -> Result<(), FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let field_names: [&'static str; 4] = ["piece placement", "side to move", "castle rights", "en passant square"];
+
+        if fields.len() < 4 {
+            return Err(FenError::MissingField(field_names[fields.len()]));
+        }
+        if fields.len() > 6 {
+            return Err(FenError::TooManyFields);
+        }
+
+        let bb: [u64; 12] = parse_piece_placement(fields[0])?;
+
+        let side_to_move: Color = match fields[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            side => return Err(FenError::InvalidSideToMove(side.to_string())),
+        };
+
+        let castle_rights: u8 = parse_castle_rights(fields[2], &bb)?;
+        let en_passant_sq: Option<Square> = parse_en_passant(fields[3], side_to_move, &bb)?;
+
+        let halfmove_clock: u32 = match fields.get(4) {
+            Some(clock) => clock.parse().map_err(|_| FenError::InvalidHalfmoveClock(clock.to_string()))?,
+            None => 0,
+        };
+        let fullmove_number: u32 = match fields.get(5) {
+            Some(number) => match number.parse() {
+                Ok(n) if n > 0 => n,
+                _ => return Err(FenError::InvalidFullmoveNumber(number.to_string())),
+            },
+            None => 1,
+        };
+
+        self.bb = bb;
+        self.side_to_move = side_to_move;
+        self.castle_rights = castle_rights;
+        self.en_passant_sq = en_passant_sq;
+        self.halfmove_clock = halfmove_clock;
+        self.fullmove_number = fullmove_number;
+        self.update_occ();
+        self.hash = hash_position(self);
+
+        return Ok(());
+    }
+
+    /// creates the fen(Forsyth-Edwards Notation) of the current position with all 6 fields
+    fn to_fen(&self) -> String {
+        let mut placement: String = String::new();
+
+        for rank in 0..8 {
+            let mut empty_squares: u32 = 0;
+
+            for file in 0..8 {
+                let sq: Square = Square::from_rank_file(rank, file).unwrap();
+
+                match self.piece_on(sq) {
+                    Some(piece) => {
+                        if empty_squares != 0 {
+                            placement.push(char::from_digit(empty_squares, 10).unwrap());
+                            empty_squares = 0;
+                        }
+                        placement.push(piece.to_char());
+                    }
+                    None => empty_squares += 1,
+                }
+            }
+
+            if empty_squares != 0 {
+                placement.push(char::from_digit(empty_squares, 10).unwrap());
+            }
+            if rank != 7 {
+                placement.push('/');
+            }
+        }
+
+        let mut castle_rights: String = String::new();
+        for (right, char) in [(WHITE_KING_SIDE, 'K'), (WHITE_QUEEN_SIDE, 'Q'), (BLACK_KING_SIDE, 'k'), (BLACK_QUEEN_SIDE, 'q')] {
+            if self.castle_rights & right != 0 {
+                castle_rights.push(char);
+            }
+        }
+        if castle_rights.is_empty() {
+            castle_rights.push('-');
+        }
+
+        let en_passant: String = match self.en_passant_sq {
+            Some(sq) => sq.name(),
+            None => "-".to_string(),
+        };
+
+        return format!(
+            "{} {} {} {} {} {}",
+            placement,
+            if self.side_to_move == Color::White { "w" } else { "b" },
+            castle_rights,
+            en_passant,
+            self.halfmove_clock,
+            self.fullmove_number
+        );
+    }
+
+    /// updates the occ based on the current bitboard state
+    fn update_occ(&mut self) {
+        self.occ[0] = 0u64;
+        self.occ[1] = 0u64;
+        self.occ[2] = 0u64;
+
+        for i in 0..6 {
+            self.occ[0] |= self.bb[i];
+        }
+
+        for i in 6..12 {
+            self.occ[1] |= self.bb[i];
+        }
+
+        self.occ[2] = self.occ[0] | self.occ[1];
+    }
+
+    fn get_occ_idx(&mut self) -> i32 { return self.side_to_move.index() as i32; }
+}
+
+/// parses the first field of the fen into the 12 bitboards and checks if the pieces could be on the board
+fn parse_piece_placement(placement: &str) -> Result<[u64; 12], FenError> {
+    let mut bb: [u64; 12] = [0u64; 12];
+    let ranks: Vec<&str> = placement.split('/').collect();
+
+    if ranks.len() != 8 {
+        return Err(FenError::InvalidRankCount(ranks.len()));
+    }
+
+    for (rank_idx, rank) in ranks.iter().enumerate() {
+        let mut file: u32 = 0;
+
+        for char in rank.chars() {
+            // digits are the amount of empty squares, letters are pieces in the same order as the bb
+            if let Some(empty_squares) = char.to_digit(10).filter(|n| (1..=8).contains(n)) {
+                file += empty_squares;
+            } else if let Some(piece) = Piece::from_char(char) {
+                if let Some(sq) = Square::from_rank_file(rank_idx as i32, file as i32) {
+                    bb[piece.index()] |= sq.bit();
+                }
+                file += 1;
+            } else {
+                return Err(FenError::InvalidPiece(char));
+            }
+        }
+
+        if file != 8 {
+            return Err(FenError::InvalidRankLength { rank: 8 - rank_idx as u32, squares: file });
+        }
+    }
+
+    for color in Color::ALL {
+        let white: bool = color.is_white();
+
+        match bb[Piece::new(color, PieceKind::King).index()].count_ones() {
+            0 => return Err(FenError::MissingKing { white }),
+            1 => {},
+            _ => return Err(FenError::TooManyKings { white }),
+        }
+
+        let pawns: u32 = bb[Piece::new(color, PieceKind::Pawn).index()].count_ones();
+        if pawns > 8 {
+            return Err(FenError::TooManyPawns { white });
+        }
+
+        // every piece above the amount a side starts with has to be a promoted pawn
+        let promoted: u32 = [(PieceKind::Knight, 2), (PieceKind::Bishop, 2), (PieceKind::Rook, 2), (PieceKind::Queen, 1)]
+            .iter()
+            .map(|(kind, start)| bb[Piece::new(color, *kind).index()].count_ones().saturating_sub(*start))
+            .sum();
+        if promoted > 8 - pawns {
+            return Err(FenError::TooManyPromotedPieces { white });
+        }
+    }
+
+    let back_rank_pawns: u64 = (bb[0] | bb[6]) & (RANK_1 | RANK_8);
+    if back_rank_pawns != 0u64 {
+        return Err(FenError::PawnOnBackRank(Square::from_ls1b(back_rank_pawns).name()));
+    }
+
+    return Ok(bb);
+}
+
+/// parses castle rights like "KQkq" or "-" to the castle rights bits, the king and rook of every right need to be
+/// on their starting squares
+fn parse_castle_rights(field: &str, bb: &[u64; 12]) -> Result<u8, FenError> {
+    let mut castle_rights: u8 = 0;
+
+    if field == "-" {
+        return Ok(castle_rights);
+    }
+
+    for char in field.chars() {
+        let right: u8 = match char {
+            'K' => WHITE_KING_SIDE,
+            'Q' => WHITE_QUEEN_SIDE,
+            'k' => BLACK_KING_SIDE,
+            'q' => BLACK_QUEEN_SIDE,
+            _ => return Err(FenError::InvalidCastleRights(field.to_string())),
+        };
+
+        // every right may only be given once
+        if castle_rights & right != 0 {
+            return Err(FenError::InvalidCastleRights(field.to_string()));
+        }
+        castle_rights |= right;
+    }
+
+    // the king and the rook it castles with both still need to be on their starting squares
+    for (right, char, color, king_sq, rook_sq) in [
+        (WHITE_KING_SIDE, 'K', Color::White, Square::E1, Square::H1),
+        (WHITE_QUEEN_SIDE, 'Q', Color::White, Square::E1, Square::A1),
+        (BLACK_KING_SIDE, 'k', Color::Black, Square::E8, Square::H8),
+        (BLACK_QUEEN_SIDE, 'q', Color::Black, Square::E8, Square::A8),
+    ] {
+        if castle_rights & right != 0
+            && (bb[Piece::new(color, PieceKind::King).index()] & king_sq.bit() == 0u64
+                || bb[Piece::new(color, PieceKind::Rook).index()] & rook_sq.bit() == 0u64) {
+            return Err(FenError::ImpossibleCastleRight(char));
+        }
+    }
+
+    return Ok(castle_rights);
+}
+
+/// parses the en passant square, it needs to be on the 6th rank if white is to move and the 3rd rank for black.
+/// the pawn that just moved 2 squares needs to stand right behind it, and the square and the one the pawn came from
+/// need to be empty
+fn parse_en_passant(field: &str, side_to_move: Color, bb: &[u64; 12]) -> Result<Option<Square>, FenError> {
+    if field == "-" {
+        return Ok(None);
+    }
+
+    let expected_rank: i32 = if side_to_move == Color::White { 2 } else { 5 };
+    let Some(sq) = Square::from_name(field).filter(|sq| sq.rank() == expected_rank) else {
+        return Err(FenError::InvalidEnPassant(field.to_string()));
+    };
+
+    // on the 3rd/6th rank the squares in front of and behind the en passant square are always on the board
+    let pawn_sq: Square = sq.offset(-side_to_move.forward()).unwrap();
+    let start_sq: Square = sq.offset(side_to_move.forward()).unwrap();
+    let occ: u64 = bb.iter().fold(0u64, |occ, pieces| occ | pieces);
+
+    if bb[Piece::new(!side_to_move, PieceKind::Pawn).index()] & pawn_sq.bit() == 0u64
+        || occ & (sq.bit() | start_sq.bit()) != 0u64 {
+        return Err(FenError::InvalidEnPassant(field.to_string()));
+    }
+
+    return Ok(Some(sq));
+}
\ No newline at end of file
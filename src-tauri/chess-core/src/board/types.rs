@@ -0,0 +1,267 @@
+use std::fmt;
+use std::ops::Not;
+
+/// a square on the board, a8 = 0 and h1 = 63. only the 64 squares of the board can be created,
+/// so a square that is off the board is None instead of an index that has to be checked everywhere
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Square(u8);
+
+/// side of a piece, or the side that is allowed to move
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Color {
+    White,
+    Black,
+}
+
+/// type of a piece without its color, in the same order as the bitboards of a side
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PieceKind {
+    Pawn,
+    Knight,
+    Bishop,
+    Rook,
+    Queen,
+    King,
+}
+
+/// a piece of a side, index() gives the bitboard of the piece(0-5 white pawn to king, 6-11 black pawn to king)
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Piece {
+    pub color: Color,
+    pub kind: PieceKind,
+}
+
+/// iterates over the squares of a bitboard, starting at the lowest square
+pub struct Squares {
+    bb: u64,
+}
+
+impl Square {
+    pub const A8: Square = Square(0);
+    pub const B8: Square = Square(1);
+    pub const C8: Square = Square(2);
+    pub const D8: Square = Square(3);
+    pub const E8: Square = Square(4);
+    pub const F8: Square = Square(5);
+    pub const G8: Square = Square(6);
+    pub const H8: Square = Square(7);
+    pub const A1: Square = Square(56);
+    pub const B1: Square = Square(57);
+    pub const C1: Square = Square(58);
+    pub const D1: Square = Square(59);
+    pub const E1: Square = Square(60);
+    pub const F1: Square = Square(61);
+    pub const G1: Square = Square(62);
+    pub const H1: Square = Square(63);
+
+    /// returns the square of the index, None if the index isn't on the board
+    pub fn new(index: i32) -> Option<Square> {
+        return if (0..64).contains(&index) { Some(Square(index as u8)) } else { None };
+    }
+
+    /// returns the square on the rank(0 = 8th rank, 7 = 1st rank) and file(0 = a-file), None if it isn't on the board
+    pub fn from_rank_file(rank: i32, file: i32) -> Option<Square> {
+        return if (0..8).contains(&rank) && (0..8).contains(&file) { Square::new(rank * 8 + file) } else { None };
+    }
+
+    /// returns the square of the lowest 6 bits, every 6 bit number is a square on the board
+    #[inline(always)]
+    pub const fn from_bits(bits: u16) -> Square {
+        return Square((bits & 63) as u8);
+    }
+
+    /// returns the lowest square that is set on the bitboard(UNSAFE! the bitboard may not be empty)
+    #[inline(always)]
+    pub fn from_ls1b(bb: u64) -> Square {
+        debug_assert_ne!(bb, 0u64);
+
+        return Square(bb.trailing_zeros() as u8);
+    }
+
+    /// translates a square like "e3" to the square on the board
+    pub fn from_name(name: &str) -> Option<Square> {
+        let chars: Vec<char> = name.chars().collect();
+
+        if chars.len() != 2 || !('a'..='h').contains(&chars[0]) || !('1'..='8').contains(&chars[1]) {
+            return None;
+        }
+
+        let file: i32 = chars[0] as i32 - 'a' as i32;
+        let rank: i32 = chars[1] as i32 - '1' as i32;
+
+        return Square::from_rank_file(7 - rank, file);
+    }
+
+    /// all 64 squares from a8 to h1
+    pub fn all() -> Squares {
+        return Squares { bb: !0u64 };
+    }
+
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        return self.0 as usize;
+    }
+
+    /// bitboard with only this square set
+    #[inline(always)]
+    pub fn bit(self) -> u64 {
+        return 1u64 << self.0;
+    }
+
+    /// row of the square, 0 is the 8th rank and 7 the 1st rank
+    pub fn rank(self) -> i32 {
+        return self.0 as i32 / 8;
+    }
+
+    /// column of the square, 0 is the a-file and 7 the h-file
+    pub fn file(self) -> i32 {
+        return self.0 as i32 % 8;
+    }
+
+    /// returns the square delta indexes further, None if it falls off the top or bottom of the board.
+    /// NOTE: it doesn't check if the square wraps around to the other side of the board
+    pub fn offset(self, delta: i32) -> Option<Square> {
+        return Square::new(self.0 as i32 + delta);
+    }
+
+    /// name of the square, for example e3
+    pub fn name(self) -> String {
+        let file: char = (b'a' + self.file() as u8) as char;
+        let rank: char = (b'8' - self.rank() as u8) as char;
+
+        return format!("{}{}", file, rank);
+    }
+}
+
+impl From<Square> for i32 {
+    fn from(sq: Square) -> i32 {
+        return sq.0 as i32;
+    }
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.name());
+    }
+}
+
+impl Squares {
+    pub fn new(bb: u64) -> Self {
+        return Self { bb };
+    }
+}
+
+impl Iterator for Squares {
+    type Item = Square;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Square> {
+        if self.bb == 0u64 {
+            return None;
+        }
+
+        let sq: Square = Square::from_ls1b(self.bb);
+        self.bb &= self.bb - 1;
+
+        return Some(sq);
+    }
+}
+
+impl Color {
+    pub const ALL: [Color; 2] = [Color::White, Color::Black];
+
+    pub fn from_white(white: bool) -> Color {
+        return if white { Color::White } else { Color::Black };
+    }
+
+    pub fn is_white(self) -> bool {
+        return self == Color::White;
+    }
+
+    /// index of the side in the occ and the pawn masks(0 = white, 1 = black)
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        return self as usize;
+    }
+
+    /// the square index a pawn of this side moves forward with, white moves up the board towards a8
+    pub fn forward(self) -> i32 {
+        return if self == Color::White { -8 } else { 8 };
+    }
+}
+
+impl Not for Color {
+    type Output = Color;
+
+    /// the other side
+    fn not(self) -> Color {
+        return if self == Color::White { Color::Black } else { Color::White };
+    }
+}
+
+impl PieceKind {
+    pub const ALL: [PieceKind; 6] =
+        [PieceKind::Pawn, PieceKind::Knight, PieceKind::Bishop, PieceKind::Rook, PieceKind::Queen, PieceKind::King];
+    /// pieces a pawn can promote to, the best one first
+    pub const PROMOTIONS: [PieceKind; 4] = [PieceKind::Queen, PieceKind::Rook, PieceKind::Bishop, PieceKind::Knight];
+
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        return self as usize;
+    }
+
+    /// lowercase letter of the piece, for example 'n' for a knight
+    pub fn to_char(self) -> char {
+        return "pnbrqk".as_bytes()[self.index()] as char;
+    }
+
+    /// the piece of the letter, it doesn't matter if the letter is upper- or lowercase
+    pub fn from_char(char: char) -> Option<PieceKind> {
+        return "pnbrqk".find(char.to_ascii_lowercase()).map(|idx| PieceKind::ALL[idx]);
+    }
+}
+
+impl Piece {
+    pub const fn new(color: Color, kind: PieceKind) -> Piece {
+        return Piece { color, kind };
+    }
+
+    /// the piece of the bitboard index, None if it isn't between 0 and 11
+    pub fn from_index(index: i32) -> Option<Piece> {
+        if !(0..12).contains(&index) {
+            return None;
+        }
+
+        let color: Color = if index < 6 { Color::White } else { Color::Black };
+        return Some(Piece::new(color, PieceKind::ALL[index as usize % 6]));
+    }
+
+    /// all 12 pieces in the order of the bitboards
+    pub fn all() -> impl Iterator<Item = Piece> {
+        return Color::ALL.into_iter().flat_map(|color| PieceKind::ALL.map(|kind| Piece::new(color, kind)));
+    }
+
+    /// index of the bitboard of this piece
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        return self.color.index() * 6 + self.kind.index();
+    }
+
+    /// letter of the piece in a fen, white pieces are uppercase and black pieces lowercase
+    pub fn to_char(self) -> char {
+        return if self.color == Color::White { self.kind.to_char().to_ascii_uppercase() } else { self.kind.to_char() };
+    }
+
+    /// the piece of the letter in a fen
+    pub fn from_char(char: char) -> Option<Piece> {
+        let color: Color = if char.is_ascii_uppercase() { Color::White } else { Color::Black };
+
+        return PieceKind::from_char(char).map(|kind| Piece::new(color, kind));
+    }
+}
+
+impl From<Piece> for i32 {
+    fn from(piece: Piece) -> i32 {
+        return piece.index() as i32;
+    }
+}
@@ -0,0 +1,98 @@
+/*
+    explanation of zobrist hashing: https://www.chessprogramming.org/Zobrist_Hashing
+    every part of a position gets its own random number, the hash of a position is all its numbers XOR'ed together.
+    because XOR undoes itself, a move only has to XOR the numbers of what it changes in and out of the hash
+ */
+
+use std::sync::OnceLock;
+
+use crate::board::state::ChessGameState;
+use crate::board::types::{Color, Piece, Square, Squares};
+use crate::helpers::random::{RandomNumberGenerator, XorShiftRng};
+use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+
+/// seed the keys are generated with, changing it changes the hash of every position
+pub const ZOBRIST_SEED: u32 = 1070372631;
+
+/// random numbers for every part of a position
+/// <br><br>pieces: number of every piece on every square, indexed by [piece index][square index]
+/// <br><br>black_to_move: only in the hash if black is to move
+/// <br><br>castle_rights: number of every combination of the castle rights bits
+/// <br><br>en_passant_file: number of the file of the en passant square
+pub struct ZobristKeys {
+    pub pieces: [[u64; 64]; 12],
+    pub black_to_move: u64,
+    pub castle_rights: [u64; 16],
+    pub en_passant_file: [u64; 8],
+}
+
+static ZOBRIST_KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+
+impl ZobristKeys {
+    /// generates all keys with the seed, the same seed always gives the same keys
+    pub fn new(seed: u32) -> Self {
+        let mut rng = XorShiftRng::new(seed);
+        let mut keys = Self {
+            pieces: [[0u64; 64]; 12],
+            black_to_move: 0u64,
+            castle_rights: [0u64; 16],
+            en_passant_file: [0u64; 8],
+        };
+
+        for piece_keys in keys.pieces.iter_mut() {
+            piece_keys.iter_mut().for_each(|key| *key = rng.get_random_u64_number());
+        }
+        keys.black_to_move = rng.get_random_u64_number();
+        keys.castle_rights.iter_mut().for_each(|key| *key = rng.get_random_u64_number());
+        keys.en_passant_file.iter_mut().for_each(|key| *key = rng.get_random_u64_number());
+
+        return keys;
+    }
+
+    #[inline(always)]
+    pub fn piece(&self, piece: Piece, sq: Square) -> u64 {
+        return self.pieces[piece.index()][sq.index()];
+    }
+
+    /// key of the en passant square of the state, 0 if there is none.
+    /// the square only counts if the side to move can legally capture en passant, so a double push that can't be
+    /// answered with en passant(no pawn next to it, or the pawn is pinned) gives the same hash as any other move.
+    /// positions that are the same by the rules then always have the same hash
+    pub fn en_passant(&self, state: &ChessGameState) -> u64 {
+        let Some(sq) = state.en_passant_sq else {
+            return 0u64;
+        };
+
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        if !move_generator.can_capture_en_passant(state) {
+            return 0u64;
+        }
+
+        return self.en_passant_file[sq.file() as usize];
+    }
+}
+
+/// returns the zobrist keys, the first call generates them
+pub fn get_zobrist_keys() -> &'static ZobristKeys {
+    return ZOBRIST_KEYS.get_or_init(|| ZobristKeys::new(ZOBRIST_SEED));
+}
+
+/// calculates the hash of the position from scratch, make_move keeps the hash of the state up to date without it
+pub fn hash_position(state: &ChessGameState) -> u64 {
+    let keys: &ZobristKeys = get_zobrist_keys();
+    let mut hash: u64 = 0u64;
+
+    for piece in Piece::all() {
+        for sq in Squares::new(state.pieces(piece)) {
+            hash ^= keys.piece(piece, sq);
+        }
+    }
+
+    if state.side_to_move == Color::Black {
+        hash ^= keys.black_to_move;
+    }
+    hash ^= keys.castle_rights[state.castle_rights as usize];
+    hash ^= keys.en_passant(state);
+
+    return hash;
+}
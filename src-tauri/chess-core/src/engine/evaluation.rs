@@ -0,0 +1,122 @@
+/*
+    the evaluation counts the material of both sides and gives a bonus/penalty for the square every piece stands on.
+    values and tables come from the simplified evaluation function: https://www.chessprogramming.org/Simplified_Evaluation_Function
+ */
+
+use crate::board::state::ChessGameState;
+use crate::board::types::{Color, Piece, PieceKind, Squares};
+
+/// value of every piece kind in centipawns(pawn, knight, bishop, rook, queen, king), the king can't be captured
+pub const PIECE_VALUES: [i32; 6] = [100, 320, 330, 500, 900, 0];
+
+// piece square tables are seen from white, the first row is the 8th rank(the same order as the squares)
+const PAWN_TABLE: [i32; 64] = [
+     0,  0,   0,   0,   0,   0,  0,  0,
+    50, 50,  50,  50,  50,  50, 50, 50,
+    10, 10,  20,  30,  30,  20, 10, 10,
+     5,  5,  10,  25,  25,  10,  5,  5,
+     0,  0,   0,  20,  20,   0,  0,  0,
+     5, -5, -10,   0,   0, -10, -5,  5,
+     5, 10,  10, -20, -20,  10, 10,  5,
+     0,  0,   0,   0,   0,   0,  0,  0,
+];
+
+const KNIGHT_TABLE: [i32; 64] = [
+    -50, -40, -30, -30, -30, -30, -40, -50,
+    -40, -20,   0,   0,   0,   0, -20, -40,
+    -30,   0,  10,  15,  15,  10,   0, -30,
+    -30,   5,  15,  20,  20,  15,   5, -30,
+    -30,   0,  15,  20,  20,  15,   0, -30,
+    -30,   5,  10,  15,  15,  10,   5, -30,
+    -40, -20,   0,   5,   5,   0, -20, -40,
+    -50, -40, -30, -30, -30, -30, -40, -50,
+];
+
+const BISHOP_TABLE: [i32; 64] = [
+    -20, -10, -10, -10, -10, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,  10,  10,   5,   0, -10,
+    -10,   5,   5,  10,  10,   5,   5, -10,
+    -10,   0,  10,  10,  10,  10,   0, -10,
+    -10,  10,  10,  10,  10,  10,  10, -10,
+    -10,   5,   0,   0,   0,   0,   5, -10,
+    -20, -10, -10, -10, -10, -10, -10, -20,
+];
+
+const ROOK_TABLE: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+     5, 10, 10, 10, 10, 10, 10,  5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+     0,  0,  0,  5,  5,  0,  0,  0,
+];
+
+const QUEEN_TABLE: [i32; 64] = [
+    -20, -10, -10, -5, -5, -10, -10, -20,
+    -10,   0,   0,  0,  0,   0,   0, -10,
+    -10,   0,   5,  5,  5,   5,   0, -10,
+     -5,   0,   5,  5,  5,   5,   0,  -5,
+      0,   0,   5,  5,  5,   5,   0,  -5,
+    -10,   5,   5,  5,  5,   5,   0, -10,
+    -10,   0,   5,  0,  0,   0,   0, -10,
+    -20, -10, -10, -5, -5, -10, -10, -20,
+];
+
+// the king hides behind its pawns while there are still queens on the board
+const KING_MIDDLE_GAME_TABLE: [i32; 64] = [
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -20, -30, -30, -40, -40, -30, -30, -20,
+    -10, -20, -20, -20, -20, -20, -20, -10,
+     20,  20,   0,   0,   0,   0,  20,  20,
+     20,  30,  10,   0,   0,  10,  30,  20,
+];
+
+// without queens the king is safe enough to help in the center
+const KING_END_GAME_TABLE: [i32; 64] = [
+    -50, -40, -30, -20, -20, -30, -40, -50,
+    -30, -20, -10,   0,   0, -10, -20, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -30,   0,   0,   0,   0, -30, -30,
+    -50, -30, -30, -30, -30, -30, -30, -50,
+];
+
+/// score of the position in centipawns seen from the side to move, positive is good for the side to move
+pub fn evaluate(state: &ChessGameState) -> i32 {
+    let end_game: bool = state.pieces_of_kind(PieceKind::Queen) == 0u64;
+    let mut score: i32 = 0;
+
+    for piece in Piece::all() {
+        let table: &[i32; 64] = piece_square_table(piece.kind, end_game);
+
+        for sq in Squares::new(state.pieces(piece)) {
+            // black looks at the tables upside down, flipping the rank of the square mirrors it
+            let table_idx: usize = if piece.color == Color::White { sq.index() } else { sq.index() ^ 56 };
+            let piece_score: i32 = PIECE_VALUES[piece.kind.index()] + table[table_idx];
+
+            score += if piece.color == Color::White { piece_score } else { -piece_score };
+        }
+    }
+
+    return if state.side_to_move == Color::White { score } else { -score };
+}
+
+/// returns the table of bonuses for the squares the piece kind stands on
+fn piece_square_table(kind: PieceKind, end_game: bool) -> &'static [i32; 64] {
+    return match kind {
+        PieceKind::Pawn => &PAWN_TABLE,
+        PieceKind::Knight => &KNIGHT_TABLE,
+        PieceKind::Bishop => &BISHOP_TABLE,
+        PieceKind::Rook => &ROOK_TABLE,
+        PieceKind::Queen => &QUEEN_TABLE,
+        PieceKind::King => if end_game { &KING_END_GAME_TABLE } else { &KING_MIDDLE_GAME_TABLE },
+    };
+}
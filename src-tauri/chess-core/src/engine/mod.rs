@@ -0,0 +1,6 @@
+pub mod evaluation;
+pub mod move_picker;
+pub mod search;
+pub mod see;
+pub mod time_manager;
+pub mod transposition;
\ No newline at end of file
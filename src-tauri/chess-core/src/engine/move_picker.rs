@@ -0,0 +1,308 @@
+/*
+    move ordering: https://www.chessprogramming.org/Move_Ordering
+    alpha-beta only skips the other moves after a move scores beta, so the sooner the best move is searched the fewer
+    moves are searched. the move picker hands out the moves of a position in stages:
+    1. the best move of the transposition table
+    2. captures that don't lose material(SEE), the most valuable victim first and of those the least valuable attacker
+       first(MVV-LVA)
+    3. killer moves: quiet moves that scored beta in another position at the same ply
+    4. the other quiet moves, the ones that scored beta most often during the search first(history heuristic)
+    5. captures that lose material
+    a stage only sorts its moves once it's reached, when an early move scores beta the rest is never sorted
+ */
+
+use crate::board::state::ChessGameState;
+use crate::board::types::PieceKind;
+use crate::engine::search::MAX_PLY;
+use crate::engine::see::{mvv_lva, see};
+use crate::moves::move_generator::MoveGenerator;
+use crate::moves::move_interfaces::{MAX_MOVES, Move, MoveList};
+
+/// history scores always stay between -MAX_HISTORY and MAX_HISTORY
+pub const MAX_HISTORY: i32 = 16384;
+
+/// the stages in the order the moves are handed out
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Stage {
+    HashMove,
+    GoodCaptures,
+    Killers,
+    Quiets,
+    BadCaptures,
+    Done,
+}
+
+/// moves: 2 quiet moves per ply that scored beta, the newest first(Move::NULL if there is none)
+pub struct KillerMoves {
+    moves: [[Move; 2]; MAX_PLY as usize],
+}
+
+/// scores: score of every quiet move by the piece that moves and its end square. it goes up when the move scores beta
+/// and down when the move was searched before another move that scored beta
+pub struct History {
+    scores: [[i32; 64]; 12],
+}
+
+/// moves: the legal moves, the moves before idx have been handed out
+/// <br><br>scores: sort score of the move at the same index, only set for the stages that have been reached
+/// <br><br>hash_move/killers: Move::NULL if there is none
+/// <br><br>captures_only: stops after the good captures, for the quiescence search
+/// <br><br>good_end: the good captures are the moves before good_end, a capture that loses material is moved behind it
+/// <br><br>captures_end: the bad captures are the moves from good_end up to captures_end, the quiet moves come after
+/// <br><br>killers_end: the killers are moved to the start of the quiet moves, they are the moves up to killers_end
+pub struct MovePicker {
+    moves: MoveList,
+    scores: [i32; MAX_MOVES],
+    stage: Stage,
+    hash_move: Move,
+    killers: [Move; 2],
+    captures_only: bool,
+    idx: usize,
+    good_end: usize,
+    captures_end: usize,
+    killers_end: usize,
+}
+
+impl KillerMoves {
+    /// returns the killers of the ply
+    pub fn get(&self, ply: i32) -> [Move; 2] {
+        return self.moves.get(ply as usize).copied().unwrap_or([Move::NULL; 2]);
+    }
+
+    /// remembers a quiet move that scored beta at the ply, the oldest killer of the ply is forgotten
+    pub fn store(&mut self, ply: i32, chess_move: Move) {
+        let Some(killers) = self.moves.get_mut(ply as usize) else {
+            return;
+        };
+
+        if killers[0] != chess_move {
+            killers[1] = killers[0];
+            killers[0] = chess_move;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.moves = [[Move::NULL; 2]; MAX_PLY as usize];
+    }
+}
+
+impl Default for KillerMoves {
+    fn default() -> Self {
+        return Self { moves: [[Move::NULL; 2]; MAX_PLY as usize] };
+    }
+}
+
+impl History {
+    /// returns the score of the quiet move of the side to move
+    pub fn get(&self, state: &ChessGameState, chess_move: Move) -> i32 {
+        return match state.color_piece_on(state.side_to_move, chess_move.src()) {
+            Some(piece) => self.scores[piece.index()][chess_move.dest().index()],
+            None => 0,
+        };
+    }
+
+    /// adds the bonus(a penalty if it's negative) to the quiet move of the side to move,
+    /// the closer the score already is to MAX_HISTORY the less it changes
+    pub fn update(&mut self, state: &ChessGameState, chess_move: Move, bonus: i32) {
+        let Some(piece) = state.color_piece_on(state.side_to_move, chess_move.src()) else {
+            return;
+        };
+
+        let bonus: i32 = bonus.clamp(-MAX_HISTORY, MAX_HISTORY);
+        let score: &mut i32 = &mut self.scores[piece.index()][chess_move.dest().index()];
+        *score += bonus - *score * bonus.abs() / MAX_HISTORY;
+    }
+
+    /// halves every score, so the moves of the new search count more than those of earlier searches
+    pub fn age(&mut self) {
+        self.scores.iter_mut().flatten().for_each(|score| *score /= 2);
+    }
+
+    pub fn clear(&mut self) {
+        self.scores = [[0; 64]; 12];
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        return Self { scores: [[0; 64]; 12] };
+    }
+}
+
+/// bonus a quiet move gets when it scores beta, a deeper search says more about the move
+pub fn history_bonus(depth: u32) -> i32 {
+    return (depth * depth).min(MAX_HISTORY as u32) as i32;
+}
+
+/// returns true if the move changes the material on the board right away(a capture or queen promotion)
+pub fn is_tactical(chess_move: Move) -> bool {
+    return chess_move.is_capture() || chess_move.promotion() == Some(PieceKind::Queen);
+}
+
+impl MovePicker {
+    /// picks all legal moves of the position
+    pub fn new(moves: MoveList, hash_move: Move, killers: [Move; 2]) -> Self {
+        return Self {
+            moves,
+            scores: [0; MAX_MOVES],
+            stage: Stage::HashMove,
+            hash_move,
+            killers,
+            captures_only: false,
+            idx: 0,
+            good_end: 0,
+            captures_end: 0,
+            killers_end: 0,
+        };
+    }
+
+    /// only picks the captures and queen promotions that don't lose material
+    pub fn captures(moves: MoveList) -> Self {
+        return Self { captures_only: true, ..Self::new(moves, Move::NULL, [Move::NULL; 2]) };
+    }
+
+    /// returns the next move that should be searched, None if every move has been handed out.
+    /// the move generator is used for the SEE of the captures and the history for the order of the quiet moves
+    pub fn next(
+        &mut self,
+        state: &ChessGameState,
+        move_generator: &mut MoveGenerator,
+        history: &History,
+    ) -> Option<Move> {
+        loop {
+            match self.stage {
+                Stage::HashMove => {
+                    let hash_idx: Option<usize> = match self.hash_move {
+                        Move::NULL => None,
+                        hash_move => self.moves.iter().position(|m| *m == hash_move),
+                    };
+                    if let Some(hash_idx) = hash_idx {
+                        self.swap(0, hash_idx);
+                        self.idx = 1;
+                    }
+
+                    self.start_captures(state);
+                    if hash_idx.is_some() {
+                        return Some(self.hash_move);
+                    }
+                }
+                Stage::GoodCaptures => {
+                    if self.idx < self.good_end {
+                        let best_idx: usize = self.best_idx(self.good_end);
+
+                        // a capture that loses material is moved behind the good captures, it's searched last
+                        if see(move_generator, state, self.moves[best_idx]) < 0 {
+                            self.good_end -= 1;
+                            self.swap(best_idx, self.good_end);
+                            continue;
+                        }
+
+                        return Some(self.hand_out(best_idx));
+                    }
+
+                    if self.captures_only {
+                        self.stage = Stage::Done;
+                    } else {
+                        self.start_killers();
+                    }
+                }
+                Stage::Killers => {
+                    if self.idx < self.killers_end {
+                        return Some(self.hand_out(self.idx));
+                    }
+
+                    self.start_quiets(state, history);
+                }
+                Stage::Quiets => {
+                    if self.idx < self.moves.len() {
+                        let best_idx: usize = self.best_idx(self.moves.len());
+                        return Some(self.hand_out(best_idx));
+                    }
+
+                    self.idx = self.good_end;
+                    self.stage = Stage::BadCaptures;
+                }
+                Stage::BadCaptures => {
+                    if self.idx < self.captures_end {
+                        let best_idx: usize = self.best_idx(self.captures_end);
+                        return Some(self.hand_out(best_idx));
+                    }
+
+                    self.stage = Stage::Done;
+                }
+                Stage::Done => return None,
+            }
+        }
+    }
+
+    /// moves the captures in front of the quiet moves and scores them by MVV-LVA
+    fn start_captures(&mut self, state: &ChessGameState) {
+        self.captures_end = self.idx;
+
+        for idx in self.idx..self.moves.len() {
+            if is_tactical(self.moves[idx]) {
+                self.swap(idx, self.captures_end);
+                self.scores[self.captures_end] = mvv_lva(state, self.moves[self.captures_end]);
+                self.captures_end += 1;
+            }
+        }
+
+        self.good_end = self.captures_end;
+        self.stage = Stage::GoodCaptures;
+    }
+
+    /// moves the killers that are legal quiet moves in this position to the start of the quiet moves
+    fn start_killers(&mut self) {
+        self.idx = self.captures_end;
+        self.killers_end = self.idx;
+
+        for killer in self.killers {
+            let quiets: &[Move] = &self.moves.as_slice()[self.killers_end..];
+            if let Some(quiet_idx) = quiets.iter().position(|m| *m == killer && killer != Move::NULL) {
+                self.swap(self.killers_end, self.killers_end + quiet_idx);
+                self.killers_end += 1;
+            }
+        }
+
+        self.stage = Stage::Killers;
+    }
+
+    /// scores the quiet moves by their history, under promotions are searched after every other quiet move
+    fn start_quiets(&mut self, state: &ChessGameState, history: &History) {
+        for idx in self.idx..self.moves.len() {
+            let chess_move: Move = self.moves[idx];
+            self.scores[idx] = match chess_move.promotion() {
+                Some(_) => -MAX_HISTORY - 1,
+                None => history.get(state, chess_move),
+            };
+        }
+
+        self.stage = Stage::Quiets;
+    }
+
+    /// index of the move with the highest score from idx up to end, the first one if there are more
+    fn best_idx(&self, end: usize) -> usize {
+        let mut best_idx: usize = self.idx;
+
+        for idx in self.idx + 1..end {
+            if self.scores[idx] > self.scores[best_idx] {
+                best_idx = idx;
+            }
+        }
+
+        return best_idx;
+    }
+
+    /// moves the move to idx and hands it out
+    fn hand_out(&mut self, move_idx: usize) -> Move {
+        self.swap(self.idx, move_idx);
+        self.idx += 1;
+
+        return self.moves[self.idx - 1];
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.moves.as_mut_slice().swap(a, b);
+        self.scores.swap(a, b);
+    }
+}
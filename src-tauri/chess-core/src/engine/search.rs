@@ -0,0 +1,328 @@
+/*
+    negamax alpha-beta search: https://www.chessprogramming.org/Alpha-Beta
+    every side picks the move that is best for itself, so the score of a position is minus the best score of the opponent.
+    alpha is the score the side to move is already sure of and beta the score the opponent is already sure of,
+    if a move scores beta or more the opponent won't allow the position and the other moves don't need to be searched.
+
+    the search is iterative deepening: it searches 1 move deep, then 2, etc. until the time is up.
+    an iteration that is stopped halfway isn't trusted, the best move of the last finished iteration is played
+ */
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::board::state::ChessGameState;
+use crate::engine::evaluation::evaluate;
+use crate::engine::move_picker::{History, history_bonus, is_tactical, KillerMoves, MovePicker};
+use crate::engine::time_manager::{SearchLimits, TimeManager};
+use crate::engine::transposition::{
+    Bound, DEFAULT_TT_SIZE_MB, is_mate_score, score_from_tt, TranspositionTable, TTEntry,
+};
+use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+use crate::moves::move_interfaces::{AddMove, Move, MoveList};
+use crate::moves::move_maker::{make_move, unmake_move};
+
+/// score of checkmating right now, a mate further away scores 1 less per ply so the quickest mate is preferred
+pub const MATE_SCORE: i32 = 30000;
+/// higher than any score a position can get
+pub const INFINITY: i32 = 32000;
+/// deepest iteration the search will ever start
+pub const MAX_DEPTH: u32 = 64;
+/// most moves the search(including the quiescence search) ever goes from the root
+pub const MAX_PLY: i32 = 128;
+/// time the computer thinks about a move when no limits are given
+pub const DEFAULT_MOVE_TIME: Duration = Duration::from_millis(1000);
+/// the clock and the stop flag are only checked once every this many nodes(needs to be a power of 2)
+const CHECK_INTERVAL: u64 = 1024;
+
+/// best_move: None if the side to move has no legal moves
+/// <br><br>score: score of the best move in centipawns seen from the side to move
+/// <br><br>depth: depth of the last iteration that was finished
+/// <br><br>nodes: amount of positions that have been searched
+#[derive(Clone, Copy, Debug)]
+pub struct SearchResult {
+    pub best_move: Option<Move>,
+    pub score: i32,
+    pub depth: u32,
+    pub nodes: u64,
+}
+
+/// searches the best move of a position, it can be reused for every position of a game
+/// <br><br>stop: set to true(from any thread) to stop the search, see stop_handle. the search never sets it back, so
+/// whoever starts a search sets it to false first
+/// <br><br>time_manager: limits of the search that is running
+/// <br><br>transposition_table: results of earlier searched positions, it's kept between searches of the same game
+/// <br><br>killers: quiet moves that scored beta per ply, they only belong to the search that is running
+/// <br><br>history: how often every quiet move scored beta, it's kept between searches of the same game
+pub struct Search {
+    pub move_generator: MoveGenerator,
+    pub nodes: u64,
+    pub stop: Arc<AtomicBool>,
+    pub time_manager: TimeManager,
+    pub transposition_table: TranspositionTable,
+    pub killers: KillerMoves,
+    pub history: History,
+}
+
+pub trait Searcher {
+    fn search(&mut self, state: &mut ChessGameState, limits: &SearchLimits) -> SearchResult;
+    fn search_root(&mut self, state: &mut ChessGameState, moves: &mut [Move], depth: u32) -> Option<i32>;
+    fn negamax(&mut self, state: &mut ChessGameState, depth: u32, ply: i32, alpha: i32, beta: i32) -> i32;
+    fn quiescence(&mut self, state: &mut ChessGameState, ply: i32, alpha: i32, beta: i32) -> i32;
+    fn should_stop(&mut self) -> bool;
+    fn stop_handle(&self) -> Arc<AtomicBool>;
+}
+
+impl Search {
+    /// creates a search with a transposition table of at most tt_size_mb megabytes
+    pub fn new(tt_size_mb: usize) -> Self {
+        return Self {
+            move_generator: MoveGenerator { ..Default::default() },
+            nodes: 0,
+            stop: Arc::new(AtomicBool::new(false)),
+            time_manager: TimeManager { ..Default::default() },
+            transposition_table: TranspositionTable::new(tt_size_mb),
+            killers: KillerMoves::default(),
+            history: History::default(),
+        };
+    }
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        return Self::new(DEFAULT_TT_SIZE_MB);
+    }
+}
+
+impl Searcher for Search {
+    /// searches deeper and deeper until the limits are reached or the search is stopped, and returns the best move
+    /// of the last finished iteration. the state is the same after the search as before.
+    /// the stop flag isn't reset, a stop that was given before the search started ends it right away
+    fn search(&mut self, state: &mut ChessGameState, limits: &SearchLimits) -> SearchResult {
+        self.nodes = 0;
+        self.time_manager = TimeManager::new(limits);
+        self.transposition_table.new_search();
+        self.killers.clear();
+        self.history.age();
+
+        let legal_moves: MoveList = self.move_generator.generate_legal_moves(state);
+
+        // without moves the game has already ended, the score is the same as when the search would have found it
+        if legal_moves.is_empty() {
+            let score: i32 = self.negamax(state, 0, 0, -INFINITY, INFINITY);
+            return SearchResult { best_move: None, score, depth: 0, nodes: self.nodes };
+        }
+
+        // the first iteration searches the root moves in the order of the move picker, after that the best move of
+        // every iteration is moved to the front
+        let hash_move: Move = self.transposition_table.probe(state.hash).map_or(Move::NULL, |entry| entry.best_move);
+        let mut picker = MovePicker::new(legal_moves, hash_move, [Move::NULL; 2]);
+        let mut moves: Vec<Move> = vec![];
+        while let Some(chess_move) = picker.next(state, &mut self.move_generator, &self.history) {
+            moves.push(chess_move);
+        }
+
+        // if the first iteration doesn't finish there is still a move to play
+        let mut result = SearchResult { best_move: Some(moves[0]), score: 0, depth: 0, nodes: 0 };
+        let max_depth: u32 = limits.depth.unwrap_or(MAX_DEPTH).clamp(1, MAX_DEPTH);
+
+        for depth in 1..=max_depth {
+            let Some(score) = self.search_root(state, &mut moves, depth) else {
+                break;
+            };
+
+            result = SearchResult { best_move: Some(moves[0]), score, depth, nodes: self.nodes };
+
+            // a deeper search won't find a quicker mate, and there is no time to finish another iteration.
+            // when the side to move is getting mated a deeper search might still find a longer defence
+            if (is_mate_score(score) && score > 0) || self.time_manager.soft_limit_reached() {
+                break;
+            }
+        }
+
+        result.nodes = self.nodes;
+        return result;
+    }
+
+    /// searches every move depth moves deep and moves the best one to the front, so the next iteration searches
+    /// it first. returns the score of the best move, None if the search was stopped before it finished
+    fn search_root(&mut self, state: &mut ChessGameState, moves: &mut [Move], depth: u32) -> Option<i32> {
+        let mut best_idx: usize = 0;
+        let mut alpha: i32 = -INFINITY;
+
+        for (idx, chess_move) in moves.iter().enumerate() {
+            make_move(chess_move, state);
+            let score: i32 = -self.negamax(state, depth - 1, 1, -INFINITY, -alpha);
+            unmake_move(state);
+
+            if self.stop.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            if score > alpha {
+                best_idx = idx;
+                alpha = score;
+            }
+        }
+
+        // the other moves keep their order, so moves with the same score are picked the same way every iteration
+        moves[..=best_idx].rotate_right(1);
+        self.transposition_table.store(state.hash, depth, alpha, Bound::Exact, moves[0], 0);
+
+        return Some(alpha);
+    }
+
+    /// returns the score of the position for the side to move, ply is the amount of moves made since the search began.
+    /// a score of alpha or lower means the side to move has a better move earlier in the tree, beta or higher that
+    /// the opponent has. the score is meaningless if the search has been stopped
+    fn negamax(&mut self, state: &mut ChessGameState, depth: u32, ply: i32, mut alpha: i32, beta: i32) -> i32 {
+        // a position counts as a node even when the table already has its score, the quiescence search counts its own
+        if depth > 0 {
+            self.nodes += 1;
+        }
+
+        if self.should_stop() {
+            return 0;
+        }
+
+        // a repetition during the search is scored as a draw, so the side that is behind will go for it
+        if ply > 0 && (state.repetitions() >= 2 || state.halfmove_clock >= 100) {
+            return 0;
+        }
+
+        // a position that has already been searched deep enough doesn't need to be searched again,
+        // unless the stored score is only a bound that says nothing about this alpha-beta window
+        let tt_entry: Option<TTEntry> = self.transposition_table.probe(state.hash);
+        if let Some(entry) = tt_entry.filter(|entry| entry.depth as u32 >= depth) {
+            let score: i32 = score_from_tt(entry.score, ply);
+
+            match entry.bound {
+                Bound::Exact => return score,
+                Bound::Lower if score >= beta => return beta,
+                Bound::Upper if score <= alpha => return alpha,
+                _ => {}
+            }
+        }
+
+        if depth == 0 {
+            return self.quiescence(state, ply, alpha, beta);
+        }
+
+        let moves: MoveList = self.move_generator.generate_legal_moves(state);
+
+        if moves.is_empty() {
+            return if self.move_generator.is_in_check(state) { -MATE_SCORE + ply } else { 0 };
+        }
+
+        // the best move of an earlier search is the most likely to be the best again, so it's searched first
+        let hash_move: Move = tt_entry.map_or(Move::NULL, |entry| entry.best_move);
+        let mut picker = MovePicker::new(moves, hash_move, self.killers.get(ply));
+
+        let mut best_move: Move = Move::NULL;
+        let mut bound: Bound = Bound::Upper;
+        let mut quiets_searched = MoveList::default();
+
+        while let Some(chess_move) = picker.next(state, &mut self.move_generator, &self.history) {
+            make_move(&chess_move, state);
+            let score: i32 = -self.negamax(state, depth - 1, ply + 1, -beta, -alpha);
+            unmake_move(state);
+
+            if self.stop.load(Ordering::Relaxed) {
+                return 0;
+            }
+            if score >= beta {
+                // a quiet move that refutes the position is tried early in the positions next to it as well,
+                // the quiet moves that were searched before it didn't refute it and are tried later
+                if !is_tactical(chess_move) {
+                    self.killers.store(ply, chess_move);
+                    self.history.update(state, chess_move, history_bonus(depth));
+                    for quiet_move in &quiets_searched {
+                        self.history.update(state, *quiet_move, -history_bonus(depth));
+                    }
+                }
+
+                self.transposition_table.store(state.hash, depth, beta, Bound::Lower, chess_move, ply);
+                return beta;
+            }
+            if score > alpha {
+                alpha = score;
+                best_move = chess_move;
+                bound = Bound::Exact;
+            }
+            if !is_tactical(chess_move) {
+                quiets_searched.add_move_class(&chess_move);
+            }
+        }
+
+        self.transposition_table.store(state.hash, depth, alpha, bound, best_move, ply);
+        return alpha;
+    }
+
+    /// searches only captures and queen promotions until the position is quiet, so a position isn't evaluated in the
+    /// middle of an exchange(horizon effect). the side to move may stop capturing and take the evaluation(stand pat),
+    /// unless it is in check, then every move is searched
+    fn quiescence(&mut self, state: &mut ChessGameState, ply: i32, mut alpha: i32, beta: i32) -> i32 {
+        self.nodes += 1;
+
+        if self.should_stop() {
+            return 0;
+        }
+
+        let moves: MoveList = self.move_generator.generate_legal_moves(state);
+        let in_check: bool = self.move_generator.is_in_check(state);
+
+        if moves.is_empty() {
+            return if in_check { -MATE_SCORE + ply } else { 0 };
+        }
+        if ply >= MAX_PLY {
+            return evaluate(state);
+        }
+
+        if !in_check {
+            let stand_pat: i32 = evaluate(state);
+
+            if stand_pat >= beta {
+                return beta;
+            }
+            alpha = alpha.max(stand_pat);
+        }
+
+        // captures that lose material won't make the score better than standing pat, so they are skipped
+        let mut picker: MovePicker = if in_check {
+            MovePicker::new(moves, Move::NULL, [Move::NULL; 2])
+        } else {
+            MovePicker::captures(moves)
+        };
+
+        while let Some(chess_move) = picker.next(state, &mut self.move_generator, &self.history) {
+            make_move(&chess_move, state);
+            let score: i32 = -self.quiescence(state, ply + 1, -beta, -alpha);
+            unmake_move(state);
+
+            if self.stop.load(Ordering::Relaxed) {
+                return 0;
+            }
+            if score >= beta {
+                return beta;
+            }
+            alpha = alpha.max(score);
+        }
+
+        return alpha;
+    }
+
+    /// returns true if the search has been stopped, or the time is up(then it also sets the stop flag)
+    fn should_stop(&mut self) -> bool {
+        if self.nodes & (CHECK_INTERVAL - 1) == 0 && self.time_manager.hard_limit_reached() {
+            self.stop.store(true, Ordering::Relaxed);
+        }
+
+        return self.stop.load(Ordering::Relaxed);
+    }
+
+    /// returns the stop flag of the search, another thread can stop the search by setting it to true
+    fn stop_handle(&self) -> Arc<AtomicBool> {
+        return Arc::clone(&self.stop);
+    }
+}
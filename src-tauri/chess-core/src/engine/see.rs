@@ -0,0 +1,104 @@
+/*
+    static exchange evaluation(SEE): https://www.chessprogramming.org/Static_Exchange_Evaluation
+    plays out all captures on the square of a move, every time with the least valuable piece, and returns what the side
+    to move wins or loses with it. every side may stop capturing when continuing would lose material.
+    sliders behind a piece that captured are found by looking up the attacks again without the pieces that are gone
+ */
+
+use crate::board::state::ChessGameState;
+use crate::board::types::{Color, Piece, PieceKind, Square};
+use crate::engine::evaluation::PIECE_VALUES;
+use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+use crate::moves::move_interfaces::Move;
+use crate::moves::move_maker::get_en_passant_capture_sq;
+
+/// the king is worth more than everything together, so a king that captures a defended piece always loses
+const KING_SEE_VALUE: i32 = 20000;
+
+/// returns the material the side to move wins(positive) or loses(negative) with the move if both sides keep capturing
+/// on its end square while it wins them something. pins aren't looked at, a pinned piece can still capture
+pub fn see(move_generator: &mut MoveGenerator, state: &ChessGameState, chess_move: Move) -> i32 {
+    let (src, dest) = (chess_move.src(), chess_move.dest());
+    let mut side: Color = state.side_to_move;
+    let mut occ: u64 = state.occ[2] ^ src.bit();
+
+    // gains[depth] is what the side that captures at that depth wins if the exchange would stop after it
+    let mut gains: [i32; 32] = [0; 32];
+    let mut depth: usize = 0;
+
+    // the piece that is standing on dest after the capture, and can be captured next
+    let moved_kind: PieceKind = state.color_piece_on(side, src).map_or(PieceKind::Pawn, |piece| piece.kind);
+    let mut on_dest: PieceKind = chess_move.promotion().unwrap_or(moved_kind);
+
+    gains[0] = state.color_piece_on(!side, dest).map_or(0, |piece| see_value(piece.kind));
+    if chess_move.is_en_passant() {
+        gains[0] = see_value(PieceKind::Pawn);
+        occ ^= get_en_passant_capture_sq(dest, side).bit();
+    }
+    if let Some(kind) = chess_move.promotion() {
+        gains[0] += see_value(kind) - see_value(PieceKind::Pawn);
+    }
+
+    loop {
+        side = !side;
+        let attackers: u64 = move_generator.get_attackers_to(dest, occ, state) & state.occ[side.index()];
+        let Some((attacker_sq, attacker_kind)) = least_valuable_attacker(attackers, side, state) else {
+            break;
+        };
+
+        depth += 1;
+        gains[depth] = see_value(on_dest) - gains[depth - 1];
+
+        // there are only 32 pieces, so this only stops the exchange when the board would be empty
+        if depth == gains.len() - 1 {
+            break;
+        }
+
+        occ ^= attacker_sq.bit();
+        on_dest = attacker_kind;
+    }
+
+    // every side only captures if it's better than stopping, from the last capture back to the first
+    while depth > 0 {
+        gains[depth - 1] = -(-gains[depth - 1]).max(gains[depth]);
+        depth -= 1;
+    }
+
+    return gains[0];
+}
+
+/// returns true if the side to move doesn't lose material with the move, so it's safe to capture with it
+pub fn is_capture_safe(move_generator: &mut MoveGenerator, state: &ChessGameState, chess_move: Move) -> bool {
+    return see(move_generator, state, chess_move) >= 0;
+}
+
+/// sort score of a capture(MVV-LVA): the most valuable victim first, of the same victims the least valuable attacker
+/// first. a promotion counts as capturing the difference between the new piece and the pawn
+pub fn mvv_lva(state: &ChessGameState, chess_move: Move) -> i32 {
+    let victim: Option<PieceKind> = if chess_move.is_en_passant() {
+        Some(PieceKind::Pawn)
+    } else {
+        state.color_piece_on(!state.side_to_move, chess_move.dest()).map(|piece| piece.kind)
+    };
+    let mut gain: i32 = victim.map_or(0, |kind| PIECE_VALUES[kind.index()]);
+    if let Some(kind) = chess_move.promotion() {
+        gain += PIECE_VALUES[kind.index()] - PIECE_VALUES[PieceKind::Pawn.index()];
+    }
+
+    let attacker: PieceKind = state.color_piece_on(state.side_to_move, chess_move.src())
+        .map_or(PieceKind::Pawn, |piece| piece.kind);
+    return gain * 8 - attacker.index() as i32;
+}
+
+/// returns the square and kind of the least valuable piece of the side between the attackers, None if there is none
+fn least_valuable_attacker(attackers: u64, side: Color, state: &ChessGameState) -> Option<(Square, PieceKind)> {
+    return PieceKind::ALL.into_iter().find_map(|kind| {
+        let pieces: u64 = attackers & state.pieces(Piece::new(side, kind));
+
+        return if pieces != 0u64 { Some((Square::from_ls1b(pieces), kind)) } else { None };
+    });
+}
+
+fn see_value(kind: PieceKind) -> i32 {
+    return if kind == PieceKind::King { KING_SEE_VALUE } else { PIECE_VALUES[kind.index()] };
+}
@@ -0,0 +1,84 @@
+/*
+    decides how long a search may take. the soft limit is checked between iterations of the search(a new depth isn't
+    started after it), the hard limit stops the search in the middle of an iteration
+ */
+
+use std::time::{Duration, Instant};
+
+/// time kept back on the clock for everything that happens outside the search(sending the move, drawing the board)
+pub const MOVE_OVERHEAD: Duration = Duration::from_millis(20);
+/// amount of moves the remaining time is divided over if moves_to_go isn't given
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+/// what the search is allowed to use, the search runs until it's stopped if nothing is set
+/// <br><br>depth: deepest iteration that will be searched
+/// <br><br>move_time: exact time the search may take, the clock is ignored if it's set
+/// <br><br>time_left: time on the clock of the side to move
+/// <br><br>increment: time that is added to the clock after the move
+/// <br><br>moves_to_go: moves that need to be made before the clock gets more time(None if it never does)
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SearchLimits {
+    pub depth: Option<u32>,
+    pub move_time: Option<Duration>,
+    pub time_left: Option<Duration>,
+    pub increment: Duration,
+    pub moves_to_go: Option<u32>,
+}
+
+/// start: moment the search started
+/// <br><br>soft_limit: no new iteration is started after this time(None if there is no limit)
+/// <br><br>hard_limit: the search stops after this time, even in the middle of an iteration(None if there is no limit)
+#[derive(Clone, Copy, Debug)]
+pub struct TimeManager {
+    pub start: Instant,
+    pub soft_limit: Option<Duration>,
+    pub hard_limit: Option<Duration>,
+}
+
+impl TimeManager {
+    /// starts the clock of a search with the limits
+    pub fn new(limits: &SearchLimits) -> Self {
+        let (soft_limit, hard_limit) = match (limits.move_time, limits.time_left) {
+            (Some(move_time), _) => (Some(move_time), Some(move_time)),
+            (None, Some(time_left)) => {
+                let (soft_limit, hard_limit) = allocate_time(time_left, limits.increment, limits.moves_to_go);
+                (Some(soft_limit), Some(hard_limit))
+            }
+            (None, None) => (None, None),
+        };
+
+        return Self { start: Instant::now(), soft_limit, hard_limit };
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        return self.start.elapsed();
+    }
+
+    /// returns true if there is no time left to start another iteration
+    pub fn soft_limit_reached(&self) -> bool {
+        return self.soft_limit.is_some_and(|limit| self.elapsed() >= limit);
+    }
+
+    /// returns true if the search has to stop right away
+    pub fn hard_limit_reached(&self) -> bool {
+        return self.hard_limit.is_some_and(|limit| self.elapsed() >= limit);
+    }
+}
+
+impl Default for TimeManager {
+    fn default() -> Self {
+        return Self::new(&SearchLimits { ..Default::default() });
+    }
+}
+
+/// divides the time on the clock over the moves that still need to be made, returns the (soft, hard) limit.
+/// a search may take up to 3 times its share if the iteration isn't done yet, but never more than the clock has left
+fn allocate_time(time_left: Duration, increment: Duration, moves_to_go: Option<u32>) -> (Duration, Duration) {
+    let available: Duration = time_left.saturating_sub(MOVE_OVERHEAD);
+    let moves_to_go: u32 = moves_to_go.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+
+    let soft_limit: Duration = (available / moves_to_go + increment).min(available);
+    let hard_limit: Duration = (soft_limit * 3).min(available);
+
+    return (soft_limit, hard_limit);
+}
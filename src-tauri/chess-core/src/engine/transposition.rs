@@ -0,0 +1,158 @@
+/*
+    the transposition table remembers the result of every searched position by its zobrist hash:
+    https://www.chessprogramming.org/Transposition_Table
+    the same position is often reached with moves in a different order, with the table it only needs to be searched once.
+    the table has a fixed size, the hash decides which entry a position is stored in and a new position can replace an
+    old one in the same entry
+ */
+
+use std::mem::size_of;
+
+use crate::engine::search::{MATE_SCORE, MAX_PLY};
+use crate::moves::move_interfaces::Move;
+
+/// size of the table in megabytes if no size is given
+pub const DEFAULT_TT_SIZE_MB: usize = 16;
+
+/// what the stored score says about the real score of the position
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Bound {
+    /// the score is the real score
+    Exact,
+    /// the search stopped after a move scored beta, the real score is at least the score
+    Lower,
+    /// no move scored better than alpha, the real score is at most the score
+    Upper,
+}
+
+/// key: full hash of the position, the index only uses the lowest bits so it's checked to be the same position
+/// <br><br>best_move: best move that was found(Move::NULL if none)
+/// <br><br>score: score of the position, mate scores are counted from the position instead of from the root
+/// <br><br>depth: how deep the position has been searched
+/// <br><br>age: the search the entry was stored in, entries of earlier searches are replaced first
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TTEntry {
+    pub key: u64,
+    pub best_move: Move,
+    pub score: i32,
+    pub depth: u8,
+    pub bound: Bound,
+    pub age: u32,
+}
+
+/// entries: the amount of entries is always a power of 2, so the index is the lowest bits of the hash
+/// <br><br>age: goes up by 1 every search. a u8 would wrap after 256 searches and make entries of a very old search
+/// look current, a u32 never wraps in practice and the entry is just as big(24 bytes)
+pub struct TranspositionTable {
+    entries: Vec<TTEntry>,
+    age: u32,
+}
+
+impl Default for TTEntry {
+    fn default() -> Self {
+        return Self { key: 0u64, best_move: Move::NULL, score: 0, depth: 0, bound: Bound::Upper, age: 0 };
+    }
+}
+
+impl TranspositionTable {
+    /// creates an empty table that uses at most size_mb megabytes(at least 1 entry)
+    pub fn new(size_mb: usize) -> Self {
+        let max_entries: usize = (size_mb * 1024 * 1024 / size_of::<TTEntry>()).max(1);
+
+        // the largest power of 2 that fits
+        let entry_count: usize = 1usize << (usize::BITS - 1 - max_entries.leading_zeros());
+
+        return Self { entries: vec![TTEntry { ..Default::default() }; entry_count], age: 0 };
+    }
+
+    /// amount of positions the table can hold
+    pub fn len(&self) -> usize {
+        return self.entries.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.entries.is_empty();
+    }
+
+    /// throws away the table and creates an empty one of the new size
+    pub fn resize(&mut self, size_mb: usize) {
+        *self = Self::new(size_mb);
+    }
+
+    /// forgets every position, used when a new game starts
+    pub fn clear(&mut self) {
+        self.entries.fill(TTEntry { ..Default::default() });
+        self.age = 0;
+    }
+
+    /// marks the entries that are stored from now on as newer than everything in the table
+    pub fn new_search(&mut self) {
+        self.age = self.age.wrapping_add(1);
+    }
+
+    /// returns the entry of the position, None if the position isn't in the table
+    #[inline(always)]
+    pub fn probe(&self, key: u64) -> Option<TTEntry> {
+        let entry: TTEntry = self.entries[self.index(key)];
+
+        return if entry.key == key { Some(entry) } else { None };
+    }
+
+    /// stores the result of a search of the position, ply is the amount of moves from the root to the position.
+    /// an entry of the current search that was searched deeper is kept, unless it is the same position
+    pub fn store(&mut self, key: u64, depth: u32, score: i32, bound: Bound, best_move: Move, ply: i32) {
+        let idx: usize = self.index(key);
+        let entry: &mut TTEntry = &mut self.entries[idx];
+
+        if entry.key != key && entry.age == self.age && entry.depth as u32 > depth {
+            return;
+        }
+
+        // a position without a best move keeps the move that was found earlier
+        let best_move: Move = if best_move == Move::NULL && entry.key == key { entry.best_move } else { best_move };
+
+        *entry = TTEntry {
+            key,
+            best_move,
+            score: score_to_tt(score, ply),
+            depth: depth.min(u8::MAX as u32) as u8,
+            bound,
+            age: self.age,
+        };
+    }
+
+    #[inline(always)]
+    fn index(&self, key: u64) -> usize {
+        return key as usize & (self.entries.len() - 1);
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        return Self::new(DEFAULT_TT_SIZE_MB);
+    }
+}
+
+/// returns true if the score is a checkmate found by the search
+pub fn is_mate_score(score: i32) -> bool {
+    return score.abs() >= MATE_SCORE - MAX_PLY;
+}
+
+/// a mate score counts the moves from the root, the table counts them from the position itself.
+/// so the same position reached at a different ply still gets the right distance to mate
+pub fn score_to_tt(score: i32, ply: i32) -> i32 {
+    return match score {
+        score if is_mate_score(score) && score > 0 => score + ply,
+        score if is_mate_score(score) => score - ply,
+        score => score,
+    };
+}
+
+/// turns the score of the table back to a score counted from the root, the opposite of score_to_tt
+pub fn score_from_tt(score: i32, ply: i32) -> i32 {
+    return match score {
+        score if is_mate_score(score) && score > 0 => score - ply,
+        score if is_mate_score(score) => score + ply,
+        score => score,
+    };
+}
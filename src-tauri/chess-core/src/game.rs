@@ -0,0 +1,247 @@
+use serde::Serialize;
+
+use crate::board::bitboard::constants::LIGHT_SQUARES;
+use crate::board::fen::FenError;
+use crate::board::state::{ChessGameState, GameStateParser};
+use crate::board::types::{Color, PieceKind, Square, Squares};
+use crate::engine::search::{Search, Searcher};
+use crate::engine::see::is_capture_safe;
+use crate::engine::time_manager::SearchLimits;
+use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+use crate::moves::move_interfaces::{Move, MoveList};
+use crate::moves::move_maker::make_move;
+use crate::moves::move_validator::validate_moves;
+
+/// state of the game for the side to move, send to the front-end so it knows when and how the game has ended
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+pub enum GameStatus {
+    Ongoing,
+    /// the game goes on, but the side to move may claim a draw(see claim_draw)
+    DrawClaimable(DrawReason),
+    /// the side to move is in check and has no legal moves
+    Checkmate { white_won: bool },
+    /// the side to move isn't in check but has no legal moves
+    Stalemate,
+    Draw(DrawReason),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+pub enum DrawReason {
+    /// neither side has enough pieces left to ever checkmate
+    InsufficientMaterial,
+    /// the same position has been on the board 3 times, the game only ends if a draw is claimed
+    ThreefoldRepetition,
+    /// the same position has been on the board 5 times
+    FivefoldRepetition,
+    /// 50 moves of both sides without a capture or pawn move, the game only ends if a draw is claimed
+    FiftyMoveRule,
+    /// 75 moves of both sides without a capture or pawn move
+    SeventyFiveMoveRule,
+}
+
+/// claimed_draw: draw the players have agreed on by claiming it, the game has ended if it is set
+/// <br><br>search: the engine the computer finds its moves with
+pub struct Game{
+    pub game_state: ChessGameState,
+    pub move_generator: MoveGenerator,
+    pub default_fen: String,
+    pub claimed_draw: Option<DrawReason>,
+    pub search: Search,
+}
+
+impl Game {
+    pub fn get_game_state_mut(&mut self) -> &mut ChessGameState {
+        &mut self.game_state
+    }
+    pub fn get_move_gen_mut(&mut self) -> &mut MoveGenerator {
+        &mut self.move_generator
+    }
+}
+
+pub trait GameHandler {
+    fn init_game(&mut self);
+    fn new_game(&mut self, fen: Option<String>) -> Result<(), FenError>;
+    fn set_position(&mut self, fen: &str) -> Result<(), FenError>;
+    fn get_moves(&mut self) -> MoveList;
+    fn get_legal_moves(&mut self) -> MoveList;
+    fn move_piece(&mut self, chess_move: Move);
+    fn validate_moves(&mut self, unvalidated_moves: MoveList) -> MoveList;
+    fn move_made_in_diff(&mut self, occ_diff: u64, start_square: Square) -> Vec<i32>;
+    fn get_game_status(&mut self) -> GameStatus;
+    fn claim_draw(&mut self) -> GameStatus;
+    fn find_best_move(&mut self, limits: &SearchLimits) -> Option<Move>;
+    fn is_capture_safe(&mut self, chess_move: Move) -> bool;
+    fn insufficient_material(&self) -> bool;
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        let game_state = ChessGameState { ..Default::default() };
+        let move_generator = MoveGenerator { ..Default::default() };
+        let default_fen : String = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string();
+        return Self {
+            game_state,
+            move_generator,
+            default_fen,
+            claimed_draw: None,
+            search: Search { ..Default::default() },
+        }
+    }
+}
+
+impl GameHandler for Game {
+    /// initializes game with default_fen
+    fn init_game(&mut self) {
+        self.new_game(None).expect("default fen is invalid");
+    }
+
+    /// throws away the current game and starts a new one from the fen, or the default_fen if none is given
+    fn new_game(&mut self, fen: Option<String>) -> Result<(), FenError> {
+        let fen: String = fen.unwrap_or(self.default_fen.clone());
+
+        return self.set_position(&fen);
+    }
+
+    /// replaces the current game(including its history and the searched positions) with the position of the fen.
+    /// the game isn't changed if the fen is invalid or describes a position that can't be reached
+    fn set_position(&mut self, fen: &str) -> Result<(), FenError> {
+        let mut game_state = ChessGameState { ..Default::default() };
+        game_state.parse_fen(fen)?;
+
+        // if the side to move can capture the king of the opponent, the opponent was allowed to stay in check
+        if self.move_generator.is_king_attacked(!game_state.side_to_move, &game_state) {
+            return Err(FenError::SideNotToMoveInCheck);
+        }
+
+        self.game_state = game_state;
+        self.claimed_draw = None;
+        self.search.transposition_table.clear();
+        self.search.history.clear();
+        return Ok(());
+    }
+
+    /// generates and returns all moves(maybe not legal move!)
+    fn get_moves(&mut self) -> MoveList {
+        return self.move_generator.generate_moves(&mut self.game_state);
+    }
+
+    /// generates and returns only the legal moves of the side to move
+    fn get_legal_moves(&mut self) -> MoveList {
+        return self.move_generator.generate_legal_moves(&mut self.game_state);
+    }
+
+    /// move a piece on the chess_board
+    fn move_piece(&mut self, chess_move: Move) {
+        make_move(&chess_move, &mut self.game_state)
+    }
+
+    /// returns all legal moves allowed to be made by a user
+    fn validate_moves(&mut self, unvalidated_moves: MoveList) -> MoveList{
+        validate_moves(unvalidated_moves, &mut self.move_generator, &mut self.game_state)
+    }
+
+    /// translates the bb to 2 square indexes so we now the start_square, and end_square
+    /// <br>returns [end_square, start_square, piece on the end_square(-1 if none)], indexes so they can be send to the front-end
+    fn move_made_in_diff(&mut self, occ_diff: u64, start_square: Square) -> Vec<i32> {
+        // loop over occ_diff and push all bits set
+        let mut move_squares: Vec<Square> = Squares::new(occ_diff).collect();
+
+        // we want to return end_sqaure -> start_square to undo a move
+        if move_squares[0] == start_square {
+            move_squares.reverse();
+        }
+        let piece: i32 = self.game_state.piece_on(move_squares[0]).map_or(-1, i32::from);
+
+        let mut move_squares: Vec<i32> = move_squares.into_iter().map(i32::from).collect();
+        move_squares.push(piece);
+
+        return move_squares;
+    }
+
+    /// returns if the game is still going on, and if not how it ended.
+    /// the game state is the same after calling this as before
+    fn get_game_status(&mut self) -> GameStatus {
+        if let Some(reason) = self.claimed_draw {
+            return GameStatus::Draw(reason);
+        }
+
+        let legal_moves: MoveList = self.get_legal_moves();
+
+        if legal_moves.is_empty() {
+            return if self.move_generator.is_in_check(&self.game_state) {
+                GameStatus::Checkmate { white_won: self.game_state.side_to_move == Color::Black }
+            } else {
+                GameStatus::Stalemate
+            };
+        }
+
+        if self.insufficient_material() {
+            return GameStatus::Draw(DrawReason::InsufficientMaterial);
+        }
+
+        // the halfmove clock counts the moves of both sides
+        let repetitions: u32 = self.game_state.repetitions();
+        let halfmove_clock: u32 = self.game_state.halfmove_clock;
+
+        if repetitions >= 5 {
+            return GameStatus::Draw(DrawReason::FivefoldRepetition);
+        }
+        if halfmove_clock >= 150 {
+            return GameStatus::Draw(DrawReason::SeventyFiveMoveRule);
+        }
+        if repetitions >= 3 {
+            return GameStatus::DrawClaimable(DrawReason::ThreefoldRepetition);
+        }
+        if halfmove_clock >= 100 {
+            return GameStatus::DrawClaimable(DrawReason::FiftyMoveRule);
+        }
+
+        return GameStatus::Ongoing;
+    }
+
+    /// ends the game in a draw if the side to move is allowed to claim one, returns the status after the claim
+    fn claim_draw(&mut self) -> GameStatus {
+        if let GameStatus::DrawClaimable(reason) = self.get_game_status() {
+            self.claimed_draw = Some(reason);
+        }
+
+        return self.get_game_status();
+    }
+
+    /// searches the best move for the side to move without making it, None if the game has ended
+    fn find_best_move(&mut self, limits: &SearchLimits) -> Option<Move> {
+        if !matches!(self.get_game_status(), GameStatus::Ongoing | GameStatus::DrawClaimable(_)) {
+            return None;
+        }
+
+        return self.search.search(&mut self.game_state, limits).best_move;
+    }
+
+    /// returns true if the side to move doesn't lose material when the move is answered with the best captures
+    fn is_capture_safe(&mut self, chess_move: Move) -> bool {
+        return is_capture_safe(&mut self.move_generator, &self.game_state, chess_move);
+    }
+
+    /// returns true if there are only kings left, a single knight/bishop extra or bishops that are all on the same
+    /// color squares
+    fn insufficient_material(&self) -> bool {
+        let state: &ChessGameState = &self.game_state;
+
+        // pawns, rooks and queens can always still checkmate
+        let mating_material: u64 = state.pieces_of_kind(PieceKind::Pawn)
+            | state.pieces_of_kind(PieceKind::Rook)
+            | state.pieces_of_kind(PieceKind::Queen);
+        if mating_material != 0u64 {
+            return false;
+        }
+
+        let knights: u32 = state.pieces_of_kind(PieceKind::Knight).count_ones();
+        let bishops: u64 = state.pieces_of_kind(PieceKind::Bishop);
+
+        if knights + bishops.count_ones() <= 1 {
+            return true;
+        }
+
+        return knights == 0 && (bishops & LIGHT_SQUARES == 0u64 || bishops & !LIGHT_SQUARES == 0u64);
+    }
+}
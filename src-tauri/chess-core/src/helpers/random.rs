@@ -0,0 +1,68 @@
+/*
+    explanation of how the XORSHIFT random number works: https://en.wikipedia.org/wiki/Xorshift
+ */
+
+///generated in python with random.randint(0, u32::max-1)
+pub const DEFAULT_SEED: u32 = 892777658;
+
+/// pseudo random number generator based on the XORSHIFT method.
+/// every generator has its own state, so it can be used on any thread and the same seed always gives the same numbers
+#[derive(Clone)]
+pub struct XorShiftRng {
+    state: u32,
+}
+
+pub trait RandomNumberGenerator {
+    fn get_random_u32_number(&mut self) -> u32;
+    fn get_random_u64_number(&mut self) -> u64;
+    fn generate_magic_number(&mut self) -> u64;
+}
+
+impl XorShiftRng {
+    /// creates a generator from the seed, the seed may not be 0 because xorshift would only give 0's
+    pub fn new(seed: u32) -> Self {
+        assert_ne!(seed, 0, "xorshift can't be seeded with 0");
+
+        return Self { state: seed };
+    }
+}
+
+impl Default for XorShiftRng {
+    fn default() -> Self {
+        return Self::new(DEFAULT_SEED);
+    }
+}
+
+impl RandomNumberGenerator for XorShiftRng {
+    ///random u32 number based on XORSHIFT method
+    fn get_random_u32_number(&mut self) -> u32 {
+        let mut number: u32 = self.state;
+
+        number ^= number << 13;
+        number ^= number >> 17;
+        number ^= number << 5;
+
+        self.state = number;
+
+        return number;
+    }
+
+    /// generates a fast pseudo random u64 number based on 4 pseudo random u32 numbers
+    /// we use the first 16 bits of the u32 number to unsure its randomness
+    fn get_random_u64_number(&mut self) -> u64 {
+        let (n1, n2, n3, n4): (u64, u64, u64, u64);
+
+        // 0xFFFF = first 16 bits of a board
+        n1 = (self.get_random_u32_number() & 0xFFFF) as u64;
+        n2 = (self.get_random_u32_number() & 0xFFFF) as u64;
+        n3 = (self.get_random_u32_number() & 0xFFFF) as u64;
+        n4 = (self.get_random_u32_number() & 0xFFFF) as u64;
+
+        return n1 | (n2 << 16) | (n3 << 32) | (n4 << 48);
+    }
+
+    /// generates a pseudo random number with only a few bits set, which are the best candidates for magic numbers
+    fn generate_magic_number(&mut self) -> u64 {
+        return self.get_random_u64_number() & self.get_random_u64_number() & self.get_random_u64_number();
+    }
+}
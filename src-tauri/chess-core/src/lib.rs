@@ -0,0 +1,11 @@
+//! everything needed to play a game of chess(board, pieces, move generation and game rules) without Tauri,
+//! the Tauri app and other tools/front-ends use this crate to talk to the chess engine
+
+pub mod pieces;
+pub mod moves;
+pub mod helpers;
+pub mod board;
+pub mod game;
+pub mod engine;
+#[cfg(test)]
+mod test;
@@ -11,7 +11,8 @@ use num_traits::WrappingShr;
 
 use crate::board::bitboard::constants::{BISHOP_MAGIC, BISHOP_RELEVANT_BITS, ROOK_MAGIC, ROOK_RELEVANT_BITS};
 use crate::board::bitboard::math::set_occ;
-use crate::helpers::random::generate_magic_number;
+use crate::board::types::Square;
+use crate::helpers::random::{RandomNumberGenerator, XorShiftRng};
 use crate::pieces::bishop::bishop::Bishop;
 use crate::pieces::piece_interfaces::SlidingPiece;
 use crate::pieces::rook::rook::Rook;
@@ -30,14 +31,15 @@ pub trait MagicMovesInit {
 }
 
 pub trait MagicMovesGenerator {
-    fn generate_magic_num(&mut self, sq: i32, relevant_bits: i32, gen_bishop: bool) -> u64;
+    fn generate_magic_num(&mut self, sq: Square, relevant_bits: i32, gen_bishop: bool, rng: &mut XorShiftRng) -> u64;
+    fn verify_magic_num(&self, sq: Square, magic_number: u64, relevant_bits: i32, gen_bishop: bool) -> bool;
     fn generate_magic_moves(&mut self, gen_bishop: bool);
 
-    fn generate_magic_bishop(&mut self, mask: u64, relevant_total_bits: u32, square: i32, idx: i32);
-    fn generate_magic_rook(&mut self, mask: u64, relevant_total_bits: u32, square: i32, idx: i32);
+    fn generate_magic_bishop(&mut self, mask: u64, relevant_total_bits: u32, square: Square, idx: i32);
+    fn generate_magic_rook(&mut self, mask: u64, relevant_total_bits: u32, square: Square, idx: i32);
 
-    fn get_bishop_moves(&mut self, sq: i32, occ: u64) -> u64;
-    fn get_rook_moves(&mut self, sq: i32, occ: u64) -> u64;
+    fn get_bishop_moves(&self, sq: Square, occ: u64) -> u64;
+    fn get_rook_moves(&self, sq: Square, occ: u64) -> u64;
 
 }
 
@@ -49,10 +51,11 @@ impl Default for MagicMoves {
         bishop.init();
         rook.init();
 
-        // All possible magics per piece per square
+        // every square only gets the entries its relevant bits can index, so magic numbers that use less bits than the
+        // mask has also make the tables smaller
         return Self {
-            bishop_moves: vec![vec![0u64; 512]; 64],
-            rook_moves: vec![vec![0u64; 4096]; 64],
+            bishop_moves: (0..64).map(|sq| vec![0u64; 1 << BISHOP_RELEVANT_BITS[sq]]).collect(),
+            rook_moves: (0..64).map(|sq| vec![0u64; 1 << ROOK_RELEVANT_BITS[sq]]).collect(),
             bishop_magic: Vec::from(BISHOP_MAGIC),
             rook_magic: Vec::from(ROOK_MAGIC),
             bishop_generator: bishop,
@@ -63,26 +66,30 @@ impl Default for MagicMoves {
 
 impl MagicMovesGenerator for MagicMoves {
 
-    /// generates the magic number for bishop/rook on any sq
-    fn generate_magic_num(&mut self, sq: i32, relevant_bits: i32, gen_bishop: bool) -> u64 {
+    /// generates the magic number for bishop/rook on any sq, returns 0 if no magic number has been found.
+    /// relevant_bits is the size of the index in the lookup table, if it's less than the bits in the mask some
+    /// occupancies need to share an index(only possible if they have the same moves)
+    /// <br>rng: the candidate magic numbers are taken from it, so the same seed finds the same magic number
+    fn generate_magic_num(&mut self, sq: Square, relevant_bits: i32, gen_bishop: bool, rng: &mut XorShiftRng) -> u64 {
         let mut attacks: Vec<u64> = vec![0u64; 4096];
 
         let mut occ: Vec<u64> = vec![0u64; 4096];
-        let occ_idx: i32 = 1 << relevant_bits;
         let attack_mask: u64 = if gen_bishop { self.bishop_generator.get_mask(sq) } else { self.rook_generator.get_mask(sq) };
+        let mask_bits: u32 = attack_mask.count_ones();
+        let occ_idx: i32 = 1 << mask_bits;
 
         for idx in 0..occ_idx {
-            occ[idx as usize] = set_occ(attack_mask, relevant_bits as u64, idx as u64);
+            occ[idx as usize] = set_occ(attack_mask, mask_bits as u64, idx as u64);
             attacks[idx as usize] = if gen_bishop
             { self.bishop_generator.get_full_move(sq, occ[idx as usize]) } else { self.rook_generator.get_full_move(sq, occ[idx as usize]) }
         }
 
         // max amount of loops we need to do until we find the magic num
         for _ in 0..1000000 {
-            let mut used_attacks: Vec<u64> = vec![0u64; 4096];
+            let mut used_attacks: Vec<u64> = vec![0u64; 1 << relevant_bits];
 
             // generate a fully random magic number(it's just a ph
-            let magic_number: u64 = generate_magic_number();
+            let magic_number: u64 = rng.generate_magic_number();
             let magic_attack: Wrapping<u64> = Wrapping(attack_mask) * Wrapping(magic_number);
 
             // if magic_attack.count_ones > 6 we can always continue because it will always fail!
@@ -114,14 +121,36 @@ impl MagicMovesGenerator for MagicMoves {
             }
         }
 
-        println!("GENERATING MAGIC NUMBER FAILED!!");
         return 0u64;
     }
 
+    /// returns true if the magic number gives the right moves for every occupancy on the square
+    fn verify_magic_num(&self, sq: Square, magic_number: u64, relevant_bits: i32, gen_bishop: bool) -> bool {
+        let mut used_attacks: Vec<u64> = vec![0u64; 1 << relevant_bits];
+        let attack_mask: u64 = if gen_bishop { self.bishop_generator.get_mask(sq) } else { self.rook_generator.get_mask(sq) };
+        let mask_bits: u32 = attack_mask.count_ones();
+
+        for idx in 0..1u64 << mask_bits {
+            let occ: u64 = set_occ(attack_mask, mask_bits as u64, idx);
+            let attacks: u64 = if gen_bishop
+            { self.bishop_generator.get_full_move(sq, occ) } else { self.rook_generator.get_full_move(sq, occ) };
+            let magic_occ = Wrapping(occ) * Wrapping(magic_number);
+            let magic_index: usize = WrappingShr::wrapping_shr(&(magic_occ.0), (64 - relevant_bits) as u32) as usize;
+
+            if used_attacks[magic_index] == 0u64 {
+                used_attacks[magic_index] = attacks;
+            } else if used_attacks[magic_index] != attacks {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
     /// generates moves with all occupancies so we can just lookup the move with the occ without having to generate it
     /// at the start of this file are most links on how the math behind this works!
     fn generate_magic_moves(&mut self, gen_bishop: bool) {
-        for sq in 0..64 {
+        for sq in Square::all() {
             let mask: u64 = if gen_bishop {self.bishop_generator.get_mask(sq)} else
                                             {self.rook_generator.get_mask(sq)};
 
@@ -137,61 +166,53 @@ impl MagicMovesGenerator for MagicMoves {
     }
 
     /// generates the bishop move for the magic index
-    fn generate_magic_bishop(&mut self, mask: u64, relevant_total_bits: u32, square: i32, idx: i32) {
+    fn generate_magic_bishop(&mut self, mask: u64, relevant_total_bits: u32, square: Square, idx: i32) {
         let occ: u64 = set_occ(mask, relevant_total_bits as u64, idx as u64);
-        let magic_occ = Wrapping(occ) * Wrapping(self.bishop_magic[square as usize]);
+        let magic_occ = Wrapping(occ) * Wrapping(self.bishop_magic[square.index()]);
 
         let magic_index: u64 = WrappingShr::wrapping_shr(
-            &(magic_occ.0), (64 - BISHOP_RELEVANT_BITS[square as usize]) as u32
+            &(magic_occ.0), (64 - BISHOP_RELEVANT_BITS[square.index()]) as u32
         );
 
-        self.bishop_moves[square as usize][magic_index as usize] = self.bishop_generator.get_full_move(square, occ);
+        self.bishop_moves[square.index()][magic_index as usize] = self.bishop_generator.get_full_move(square, occ);
     }
 
     /// generates the rook move for the magic index
-    fn generate_magic_rook(&mut self, mask: u64, relevant_total_bits: u32, square: i32, idx: i32) {
+    fn generate_magic_rook(&mut self, mask: u64, relevant_total_bits: u32, square: Square, idx: i32) {
         let occ: u64 = set_occ(mask, relevant_total_bits as u64, idx as u64);
-        let magic_occ = Wrapping(occ) * Wrapping(self.rook_magic[square as usize]);
+        let magic_occ = Wrapping(occ) * Wrapping(self.rook_magic[square.index()]);
 
         let magic_index: u64 = WrappingShr::wrapping_shr(
-            &(magic_occ.0), (64 - ROOK_RELEVANT_BITS[square as usize]) as u32
+            &(magic_occ.0), (64 - ROOK_RELEVANT_BITS[square.index()]) as u32
         );
 
-        self.rook_moves[square as usize][magic_index as usize] = self.rook_generator.get_full_move(square, occ);
+        self.rook_moves[square.index()][magic_index as usize] = self.rook_generator.get_full_move(square, occ);
     }
 
     /// gets the bishop move out of the table(O(1) lookup time!)
-    fn get_bishop_moves(&mut self, sq: i32, mut occ: u64) -> u64 {
-        occ &= self.bishop_generator.mask[sq as usize];
-        occ = (Wrapping(occ) * Wrapping(self.bishop_magic[sq as usize])).0;
-        occ = WrappingShr::wrapping_shr(&occ, (64 - BISHOP_RELEVANT_BITS[sq as usize]) as u32);
+    fn get_bishop_moves(&self, sq: Square, mut occ: u64) -> u64 {
+        occ &= self.bishop_generator.mask[sq.index()];
+        occ = (Wrapping(occ) * Wrapping(self.bishop_magic[sq.index()])).0;
+        occ = WrappingShr::wrapping_shr(&occ, (64 - BISHOP_RELEVANT_BITS[sq.index()]) as u32);
 
-        return self.bishop_moves[sq as usize][occ as usize];
+        return self.bishop_moves[sq.index()][occ as usize];
     }
 
     /// gets the rook move out of the table(O(1) lookup time!)
-    fn get_rook_moves(&mut self, sq: i32, mut occ: u64) -> u64 {
-        occ &= self.rook_generator.mask[sq as usize];
-        occ = (Wrapping(occ) * Wrapping(self.rook_magic[sq as usize])).0;
-        occ = WrappingShr::wrapping_shr(&occ, (64 - ROOK_RELEVANT_BITS[sq as usize]) as u32);
+    fn get_rook_moves(&self, sq: Square, mut occ: u64) -> u64 {
+        occ &= self.rook_generator.mask[sq.index()];
+        occ = (Wrapping(occ) * Wrapping(self.rook_magic[sq.index()])).0;
+        occ = WrappingShr::wrapping_shr(&occ, (64 - ROOK_RELEVANT_BITS[sq.index()]) as u32);
 
-        return self.rook_moves[sq as usize][occ as usize];
+        return self.rook_moves[sq.index()][occ as usize];
     }
 }
 
 impl MagicMovesInit for MagicMoves {
     /// initializes magic nums for bishop and rook
     fn init(&mut self) {
-        // we do not always want to generate magic_moves due to it having the possibility of generating magic
-        // magic numbers
-        // for sq in 0..64 {
-        //     self.bishop_magic[sq as usize] =
-        //         self.generate_magic_num(sq, BISHOP_RELEVANT_BITS[sq as usize] as i32, true);
-        //
-        //     self.rook_magic[sq as usize] =
-        //         self.generate_magic_num(sq, ROOK_RELEVANT_BITS[sq as usize] as i32, false);
-        //
-        // }
+        // the magic numbers are not generated here because searching them is slow, they are generated once with the
+        // generate_magics binary(src/bin/generate_magics.rs) and stored in the constants
         self.generate_magic_moves(true);
         self.generate_magic_moves(false);
     }
@@ -3,3 +3,4 @@ pub mod magic_moves;
 pub mod move_interfaces;
 pub mod move_maker;
 pub mod move_validator;
+pub mod perft;
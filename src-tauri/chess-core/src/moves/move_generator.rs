@@ -0,0 +1,622 @@
+use std::default::Default;
+use std::sync::OnceLock;
+
+use crate::board::bitboard::constants::{RANK_1, RANK_2, RANK_7, RANK_8};
+use crate::board::state::{BLACK_KING_SIDE, BLACK_QUEEN_SIDE, ChessGameState, GameStateParser, WHITE_KING_SIDE, WHITE_QUEEN_SIDE};
+use crate::board::types::{Color, Piece, PieceKind, Square, Squares};
+use crate::moves::magic_moves::{MagicMoves, MagicMovesGenerator, MagicMovesInit};
+use crate::moves::move_interfaces::{AddMove, CAPTURE, CASTLE, DOUBLE_PUSH, EN_PASSANT, MoveList, QUIET};
+use crate::moves::move_maker::get_en_passant_capture_sq;
+use crate::pieces::king::King;
+use crate::pieces::knight::Knight;
+use crate::pieces::pawn::pawn::Pawn;
+use crate::pieces::piece_interfaces::{MultiSideMovingPiece, NonSlidingPiece};
+
+/// the masks and magic lookup tables of all pieces, they never change so they are only generated once and shared by
+/// every MoveGenerator(also between threads)
+pub struct AttackTables {
+    pub pawn_generator: Pawn,
+    pub knight_generator: Knight,
+    pub king_generator: King,
+    pub magic_generator: MagicMoves,
+}
+
+static ATTACK_TABLES: OnceLock<AttackTables> = OnceLock::new();
+
+/// squares a pawn promotes on when it moves onto them
+const PROMOTION_SQUARES: u64 = RANK_1 | RANK_8;
+
+/// creating a MoveGenerator is cheap, it only points to the shared attack tables
+#[derive(Clone, Copy)]
+pub struct MoveGenerator {
+    pub pawn_generator: &'static Pawn,
+    pub knight_generator: &'static Knight,
+    pub king_generator: &'static King,
+    pub magic_generator: &'static MagicMoves,
+}
+
+pub trait MoveCalculator {
+    fn generate_moves(&mut self, state: &mut ChessGameState) -> MoveList;
+    fn generate_legal_moves(&mut self, state: &mut ChessGameState) -> MoveList;
+    fn is_in_check(&mut self, state: &ChessGameState) -> bool;
+    fn is_king_attacked(&mut self, king_color: Color, state: &ChessGameState) -> bool;
+    fn is_square_attacked(&mut self, sq: Square, by: Color, state: &ChessGameState) -> bool;
+    fn get_attacks(&mut self, color: Color, state: &ChessGameState) -> u64;
+    fn get_attackers_to(&mut self, sq: Square, occ: u64, state: &ChessGameState) -> u64;
+    fn can_capture_en_passant(&mut self, state: &ChessGameState) -> bool;
+}
+
+trait AllPiecesCalculator {
+    fn generate_white_pawn_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_black_pawn_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_pawn_capture(&mut self, piece_sq: Square, captures: u64, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_knight_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_bishop_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_rook_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_queen_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_king_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_castle_moves(&mut self, king_sq: Square, moves: &mut MoveList, state: &mut ChessGameState);
+    fn generate_attacking_moves(
+        &mut self, start_sq: Square, attacking_moves: u64, moves: &mut MoveList, state: &mut ChessGameState,
+    );
+    fn get_attacks_with_occ(&mut self, color: Color, occ: u64, state: &ChessGameState) -> u64;
+}
+
+trait LegalMovesCalculator {
+    fn get_checkers(&mut self, king_sq: Square, state: &ChessGameState) -> u64;
+    fn get_pinned(&mut self, king_sq: Square, state: &ChessGameState) -> u64;
+    fn get_between(&mut self, sq1: Square, sq2: Square) -> u64;
+    fn get_line(&mut self, sq1: Square, sq2: Square) -> u64;
+    fn generate_legal_pawn_moves(
+        &mut self, king_sq: Square, pinned: u64, check_mask: u64, moves: &mut MoveList, state: &mut ChessGameState,
+    );
+    fn generate_legal_piece_moves(
+        &mut self, king_sq: Square, pinned: u64, check_mask: u64, moves: &mut MoveList, state: &mut ChessGameState,
+    );
+    fn is_en_passant_legal(&mut self, king_sq: Square, src: Square, state: &ChessGameState) -> bool;
+}
+
+impl MoveCalculator for MoveGenerator {
+    fn generate_moves(&mut self, state: &mut ChessGameState) -> MoveList {
+        let mut moves = MoveList::default();
+
+        if state.side_to_move == Color::White {
+            self.generate_white_pawn_moves(&mut moves, state)
+        } else {
+            self.generate_black_pawn_moves(&mut moves, state)
+        }
+
+        self.generate_knight_moves(&mut moves, state);
+        self.generate_bishop_moves(&mut moves, state);
+        self.generate_rook_moves(&mut moves, state);
+        self.generate_queen_moves(&mut moves, state);
+        self.generate_king_moves(&mut moves, state);
+
+        return moves;
+    }
+
+    /// generates only the legal moves, so they don't need to be validated afterwards.
+    /// the pieces that give check and the pieces that are pinned to the king are calculated up front,
+    /// in check only moves that capture the checker or block the check are generated and in double check only king moves
+    fn generate_legal_moves(&mut self, state: &mut ChessGameState) -> MoveList {
+        let mut moves = MoveList::default();
+        let side: Color = state.side_to_move;
+        let king_sq: Square = state.king_square(side);
+
+        // the king can't step back on a line it is checked on, so it is taken off the board for the attacked squares
+        let danger: u64 = self.get_attacks_with_occ(!side, state.occ[2] & !king_sq.bit(), state);
+        let king_moves: u64 = self.king_generator.mask[king_sq.index()] & !state.occ[side.index()] & !danger;
+        self.generate_attacking_moves(king_sq, king_moves, &mut moves, state);
+
+        let checkers: u64 = self.get_checkers(king_sq, state);
+        if checkers.count_ones() > 1 {
+            return moves;
+        }
+
+        // squares the other pieces are allowed to move to, all of them if not in check
+        let check_mask: u64 = if checkers == 0u64 {
+            self.generate_castle_moves(king_sq, &mut moves, state);
+            !0u64
+        } else {
+            checkers | self.get_between(king_sq, Square::from_ls1b(checkers))
+        };
+
+        let pinned: u64 = self.get_pinned(king_sq, state);
+        self.generate_legal_pawn_moves(king_sq, pinned, check_mask, &mut moves, state);
+        self.generate_legal_piece_moves(king_sq, pinned, check_mask, &mut moves, state);
+
+        return moves;
+    }
+
+    /// returns true if the king of the side to move is attacked
+    fn is_in_check(&mut self, state: &ChessGameState) -> bool {
+        return self.is_king_attacked(state.side_to_move, state);
+    }
+
+    /// returns true if the (white/black)king is attacked by the other side, it doesn't matter whose turn it is
+    fn is_king_attacked(&mut self, king_color: Color, state: &ChessGameState) -> bool {
+        return self.is_square_attacked(state.king_square(king_color), !king_color, state);
+    }
+
+    /// returns true if any piece of the given side attacks the square.
+    /// we look from the square outwards with every piece's moves, if we hit the same piece of the attacker it's attacked
+    fn is_square_attacked(&mut self, sq: Square, by: Color, state: &ChessGameState) -> bool {
+        let (bishops_queens, rooks_queens) = get_sliders(by, state);
+
+        // the pawn mask of the other side gives the squares a pawn of the attacker needs to stand on
+        return self.pawn_generator.mask[sq.index()][(!by).index()] & state.pieces(Piece::new(by, PieceKind::Pawn)) != 0
+            || self.knight_generator.mask[sq.index()] & state.pieces(Piece::new(by, PieceKind::Knight)) != 0
+            || self.king_generator.mask[sq.index()] & state.pieces(Piece::new(by, PieceKind::King)) != 0
+            || self.magic_generator.get_bishop_moves(sq, state.occ[2]) & bishops_queens != 0
+            || self.magic_generator.get_rook_moves(sq, state.occ[2]) & rooks_queens != 0;
+    }
+
+    /// returns every square the (white/black)pieces attack, pieces of its own side count as attacked(defended)
+    fn get_attacks(&mut self, color: Color, state: &ChessGameState) -> u64 {
+        return self.get_attacks_with_occ(color, state.occ[2], state);
+    }
+
+    /// returns the pieces of both sides that attack the square, only the pieces on occ are seen as on the board.
+    /// taking pieces out of occ uncovers the sliders behind them(x-ray attacks)
+    fn get_attackers_to(&mut self, sq: Square, occ: u64, state: &ChessGameState) -> u64 {
+        let queens: u64 = state.pieces_of_kind(PieceKind::Queen);
+        let bishops_queens: u64 = state.pieces_of_kind(PieceKind::Bishop) | queens;
+        let rooks_queens: u64 = state.pieces_of_kind(PieceKind::Rook) | queens;
+
+        let attackers: u64 = self.pawn_generator.mask[sq.index()][Color::Black.index()]
+                & state.pieces(Piece::new(Color::White, PieceKind::Pawn))
+            | self.pawn_generator.mask[sq.index()][Color::White.index()]
+                & state.pieces(Piece::new(Color::Black, PieceKind::Pawn))
+            | self.knight_generator.mask[sq.index()] & state.pieces_of_kind(PieceKind::Knight)
+            | self.king_generator.mask[sq.index()] & state.pieces_of_kind(PieceKind::King)
+            | self.magic_generator.get_bishop_moves(sq, occ) & bishops_queens
+            | self.magic_generator.get_rook_moves(sq, occ) & rooks_queens;
+
+        return attackers & occ;
+    }
+
+    /// returns true if a pawn of the side to move can legally capture on the en passant square
+    fn can_capture_en_passant(&mut self, state: &ChessGameState) -> bool {
+        let Some(en_passant_sq) = state.en_passant_sq else {
+            return false;
+        };
+
+        // a pawn attacks the en passant square from the squares an opponent pawn on it would attack
+        let side: Color = state.side_to_move;
+        let king_sq: Square = state.king_square(side);
+        let attackers: u64 = self.pawn_generator.mask[en_passant_sq.index()][(!side).index()]
+            & state.pieces(Piece::new(side, PieceKind::Pawn));
+
+        return Squares::new(attackers).any(|sq| self.is_en_passant_legal(king_sq, sq, state));
+    }
+}
+
+impl LegalMovesCalculator for MoveGenerator {
+    /// returns the pieces of the opponent that attack the king of the side to move
+    fn get_checkers(&mut self, king_sq: Square, state: &ChessGameState) -> u64 {
+        let (side, opponent) = (state.side_to_move, !state.side_to_move);
+        let (bishops_queens, rooks_queens) = get_sliders(opponent, state);
+
+        return self.pawn_generator.mask[king_sq.index()][side.index()] & state.pieces(Piece::new(opponent, PieceKind::Pawn))
+            | self.knight_generator.mask[king_sq.index()] & state.pieces(Piece::new(opponent, PieceKind::Knight))
+            | self.magic_generator.get_bishop_moves(king_sq, state.occ[2]) & bishops_queens
+            | self.magic_generator.get_rook_moves(king_sq, state.occ[2]) & rooks_queens;
+    }
+
+    /// returns the pieces of the side to move that can't leave the line between their king and a sliding piece
+    fn get_pinned(&mut self, king_sq: Square, state: &ChessGameState) -> u64 {
+        let (side, opponent) = (state.side_to_move, !state.side_to_move);
+        let (bishops_queens, rooks_queens) = get_sliders(opponent, state);
+        let opp_occ: u64 = state.occ[opponent.index()];
+        let mut pinned: u64 = 0u64;
+
+        // sliders that would attack the king if none of our own pieces were on the board
+        let snipers: u64 = self.magic_generator.get_bishop_moves(king_sq, opp_occ) & bishops_queens
+            | self.magic_generator.get_rook_moves(king_sq, opp_occ) & rooks_queens;
+
+        for sniper_sq in Squares::new(snipers) {
+            let blockers: u64 = self.get_between(king_sq, sniper_sq) & state.occ[2];
+
+            if blockers.count_ones() == 1 && blockers & state.occ[side.index()] != 0u64 {
+                pinned |= blockers;
+            }
+        }
+
+        return pinned;
+    }
+
+    /// returns the squares between 2 squares on the same rank, file or diagonal(both squares excluded)
+    fn get_between(&mut self, sq1: Square, sq2: Square) -> u64 {
+        return if sq1.rank() == sq2.rank() || sq1.file() == sq2.file() {
+            self.magic_generator.get_rook_moves(sq1, sq2.bit()) & self.magic_generator.get_rook_moves(sq2, sq1.bit())
+        } else {
+            self.magic_generator.get_bishop_moves(sq1, sq2.bit()) & self.magic_generator.get_bishop_moves(sq2, sq1.bit())
+        };
+    }
+
+    /// returns the whole rank, file or diagonal both squares are on
+    fn get_line(&mut self, sq1: Square, sq2: Square) -> u64 {
+        let squares: u64 = sq1.bit() | sq2.bit();
+
+        return if sq1.rank() == sq2.rank() || sq1.file() == sq2.file() {
+            self.magic_generator.get_rook_moves(sq1, 0u64) & self.magic_generator.get_rook_moves(sq2, 0u64) | squares
+        } else {
+            self.magic_generator.get_bishop_moves(sq1, 0u64) & self.magic_generator.get_bishop_moves(sq2, 0u64) | squares
+        };
+    }
+
+    /// generates the legal moves of the pawns of the side to move
+    fn generate_legal_pawn_moves(
+        &mut self, king_sq: Square, pinned: u64, check_mask: u64, moves: &mut MoveList, state: &mut ChessGameState,
+    ) {
+        let side: Color = state.side_to_move;
+        let piece_type: Piece = Piece::new(side, PieceKind::Pawn);
+        // squares the pawns can double push from
+        let start_rank: u64 = if side == Color::White { RANK_2 } else { RANK_7 };
+
+        for piece_sq in Squares::new(state.pieces(piece_type)) {
+            let mut allowed: u64 = check_mask;
+            if pinned & piece_sq.bit() != 0u64 {
+                allowed &= self.get_line(king_sq, piece_sq);
+            }
+
+            // pawns are never on the first or last rank, so the square in front of them is always on the board
+            let one_sq: Square = piece_sq.offset(side.forward()).unwrap();
+            if state.occ[2] & one_sq.bit() == 0u64 {
+                if allowed & one_sq.bit() != 0u64 {
+                    // pawns moving to the first or last rank can only move by promoting
+                    if PROMOTION_SQUARES & one_sq.bit() != 0u64 {
+                        moves.add_promotion_moves(piece_sq, one_sq, false);
+                    } else {
+                        moves.add_move(piece_sq, one_sq, QUIET);
+                    }
+                }
+
+                if start_rank & piece_sq.bit() != 0u64 {
+                    let two_sq: Square = one_sq.offset(side.forward()).unwrap();
+
+                    if state.occ[2] & two_sq.bit() == 0u64 && allowed & two_sq.bit() != 0u64 {
+                        moves.add_move(piece_sq, two_sq, DOUBLE_PUSH);
+                    }
+                }
+            }
+
+            let captures: u64 = self.pawn_generator.mask[piece_sq.index()][side.index()];
+            let legal_captures: u64 = captures & state.occ[(!side).index()] & allowed;
+
+            for sq in Squares::new(legal_captures) {
+                if PROMOTION_SQUARES & sq.bit() != 0u64 {
+                    moves.add_promotion_moves(piece_sq, sq, true);
+                } else {
+                    moves.add_move(piece_sq, sq, CAPTURE);
+                }
+            }
+
+            // en passant takes 2 pieces off the same rank at once, the pins don't cover that so it's checked separately
+            if let Some(en_passant_sq) = state.en_passant_sq {
+                if captures & en_passant_sq.bit() != 0u64 && self.is_en_passant_legal(king_sq, piece_sq, state) {
+                    moves.add_move(piece_sq, en_passant_sq, EN_PASSANT);
+                }
+            }
+        }
+    }
+
+    /// generates the legal moves of the knights, bishops, rooks and queens of the side to move
+    fn generate_legal_piece_moves(
+        &mut self, king_sq: Square, pinned: u64, check_mask: u64, moves: &mut MoveList, state: &mut ChessGameState,
+    ) {
+        let side: Color = state.side_to_move;
+
+        for kind in [PieceKind::Knight, PieceKind::Bishop, PieceKind::Rook, PieceKind::Queen] {
+            let piece_type: Piece = Piece::new(side, kind);
+
+            for piece_sq in Squares::new(state.pieces(piece_type)) {
+                let attacks: u64 = match kind {
+                    PieceKind::Knight => self.knight_generator.mask[piece_sq.index()],
+                    PieceKind::Bishop => self.magic_generator.get_bishop_moves(piece_sq, state.occ[2]),
+                    PieceKind::Rook => self.magic_generator.get_rook_moves(piece_sq, state.occ[2]),
+                    _ => self.magic_generator.get_bishop_moves(piece_sq, state.occ[2])
+                        | self.magic_generator.get_rook_moves(piece_sq, state.occ[2]),
+                };
+
+                // a pinned piece may only move along the line between its king and the piece pinning it
+                let mut allowed: u64 = attacks & check_mask & !state.occ[side.index()];
+                if pinned & piece_sq.bit() != 0u64 {
+                    allowed &= self.get_line(king_sq, piece_sq);
+                }
+
+                self.generate_attacking_moves(piece_sq, allowed, moves, state);
+            }
+        }
+    }
+
+    /// returns true if the king isn't attacked after the pawn on src has captured en passant
+    fn is_en_passant_legal(&mut self, king_sq: Square, src: Square, state: &ChessGameState) -> bool {
+        let (side, opponent) = (state.side_to_move, !state.side_to_move);
+        let en_passant_sq: Square = state.en_passant_sq.unwrap();
+        let captured_sq: Square = get_en_passant_capture_sq(en_passant_sq, side);
+        let occ: u64 = state.occ[2] & !src.bit() & !captured_sq.bit() | en_passant_sq.bit();
+        let (bishops_queens, rooks_queens) = get_sliders(opponent, state);
+        let opp_pawns: u64 = state.pieces(Piece::new(opponent, PieceKind::Pawn)) & !captured_sq.bit();
+
+        return self.pawn_generator.mask[king_sq.index()][side.index()] & opp_pawns == 0u64
+            && self.knight_generator.mask[king_sq.index()] & state.pieces(Piece::new(opponent, PieceKind::Knight)) == 0u64
+            && self.magic_generator.get_bishop_moves(king_sq, occ) & bishops_queens == 0u64
+            && self.magic_generator.get_rook_moves(king_sq, occ) & rooks_queens == 0u64;
+    }
+}
+
+impl AllPiecesCalculator for MoveGenerator {
+    /// returns every square the (white/black)pieces attack as if the given occ was on the board
+    fn get_attacks_with_occ(&mut self, color: Color, occ: u64, state: &ChessGameState) -> u64 {
+        let mut attacks: u64 = 0u64;
+
+        for kind in PieceKind::ALL {
+            for sq in Squares::new(state.pieces(Piece::new(color, kind))) {
+                attacks |= match kind {
+                    PieceKind::Pawn => self.pawn_generator.mask[sq.index()][color.index()],
+                    PieceKind::Knight => self.knight_generator.mask[sq.index()],
+                    PieceKind::Bishop => self.magic_generator.get_bishop_moves(sq, occ),
+                    PieceKind::Rook => self.magic_generator.get_rook_moves(sq, occ),
+                    PieceKind::Queen => self.magic_generator.get_bishop_moves(sq, occ)
+                        | self.magic_generator.get_rook_moves(sq, occ),
+                    PieceKind::King => self.king_generator.mask[sq.index()],
+                };
+            }
+        }
+
+        return attacks;
+    }
+
+    /// generates moves for all (white)pawns currently on the board
+    fn generate_white_pawn_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        let piece_type: Piece = Piece::new(Color::White, PieceKind::Pawn);
+
+        for piece_sq in Squares::new(state.pieces(piece_type)) {
+            // pawns are never on the first or last rank, so the square in front of them is always on the board
+            let one_sq: Square = piece_sq.offset(-8).unwrap();
+
+            if state.occ[2] & one_sq.bit() == 0u64 {
+                // pawns on the 7th rank can only move forward by promoting
+                if PROMOTION_SQUARES & one_sq.bit() != 0u64 {
+                    moves.add_promotion_moves(piece_sq, one_sq, false);
+                } else {
+                    moves.add_move(piece_sq, one_sq, QUIET);
+                }
+
+                if RANK_2 & piece_sq.bit() != 0u64 {
+                    let two_sq: Square = one_sq.offset(-8).unwrap();
+
+                    if state.occ[2] & two_sq.bit() == 0u64 {
+                        moves.add_move(piece_sq, two_sq, DOUBLE_PUSH);
+                    }
+                }
+            }
+
+            let captures: u64 = self.pawn_generator.mask[piece_sq.index()][Color::White.index()];
+            self.generate_pawn_capture(piece_sq, captures, moves, state);
+
+            // the pawn can capture the pawn that just double pushed next to it
+            if let Some(en_passant_sq) = state.en_passant_sq {
+                if captures & en_passant_sq.bit() != 0u64 {
+                    moves.add_move(piece_sq, en_passant_sq, EN_PASSANT);
+                }
+            }
+        }
+    }
+
+    /// generates moves for all (black)pawns currently on the board
+    fn generate_black_pawn_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        // all black pawns
+        let piece_type: Piece = Piece::new(Color::Black, PieceKind::Pawn);
+
+        // go over all black pawns until none are left
+        for piece_sq in Squares::new(state.pieces(piece_type)) {
+            let one_sq: Square = piece_sq.offset(8).unwrap();
+
+            // makes it so that pawn can go forward
+            if state.occ[2] & one_sq.bit() == 0u64 {
+                // pawns on the 2nd rank can only move forward by promoting
+                if PROMOTION_SQUARES & one_sq.bit() != 0u64 {
+                    moves.add_promotion_moves(piece_sq, one_sq, false);
+                } else {
+                    moves.add_move(piece_sq, one_sq, QUIET);
+                }
+
+                if RANK_7 & piece_sq.bit() != 0u64 {
+                    let two_sq: Square = one_sq.offset(8).unwrap();
+
+                    if state.occ[2] & two_sq.bit() == 0u64 {
+                        moves.add_move(piece_sq, two_sq, DOUBLE_PUSH);
+                    }
+                }
+            }
+
+            let captures: u64 = self.pawn_generator.mask[piece_sq.index()][Color::Black.index()];
+            // transforms capture moves on the u64 to the moves vec
+            self.generate_pawn_capture(piece_sq, captures, moves, state);
+
+            // the pawn can capture the pawn that just double pushed next to it
+            if let Some(en_passant_sq) = state.en_passant_sq {
+                if captures & en_passant_sq.bit() != 0u64 {
+                    moves.add_move(piece_sq, en_passant_sq, EN_PASSANT);
+                }
+            }
+        }
+    }
+
+    /// attacking moves for pawns that are set on the u64 will goto moves based on the game state and the piece square
+    fn generate_pawn_capture(&mut self, piece_sq: Square, captures: u64, moves: &mut MoveList, state: &mut ChessGameState) {
+        let opp_occ: u64 = state.occ[(!state.side_to_move).index()];
+
+        for sq in Squares::new(captures & opp_occ) {
+            // capturing onto the first or last rank is a promotion
+            if PROMOTION_SQUARES & sq.bit() != 0u64 {
+                moves.add_promotion_moves(piece_sq, sq, true);
+            } else {
+                moves.add_move(piece_sq, sq, CAPTURE);
+            }
+        }
+    }
+
+    /// generates all moves for the knight on the current board
+    fn generate_knight_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        // knights bb
+        let piece_type: Piece = Piece::new(state.side_to_move, PieceKind::Knight);
+
+        for knight_sq in Squares::new(state.pieces(piece_type)) {
+            let knight_moves = self.knight_generator.mask[knight_sq.index()];
+            self.generate_attacking_moves(knight_sq, knight_moves, moves, state);
+        }
+    }
+
+    /// generates all moves for the bishop on the current board
+    fn generate_bishop_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        // bishop bb
+        let piece_type: Piece = Piece::new(state.side_to_move, PieceKind::Bishop);
+
+        for bishop_sq in Squares::new(state.pieces(piece_type)) {
+            let bishop_moves = self.magic_generator.get_bishop_moves(bishop_sq, state.occ[2]);
+            self.generate_attacking_moves(bishop_sq, bishop_moves, moves, state);
+        }
+    }
+
+    /// generates all moves for the rook on the current board
+    fn generate_rook_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        // rook bb
+        let piece_type: Piece = Piece::new(state.side_to_move, PieceKind::Rook);
+
+        for rook_sq in Squares::new(state.pieces(piece_type)) {
+            let rook_moves = self.magic_generator.get_rook_moves(rook_sq, state.occ[2]);
+            self.generate_attacking_moves(rook_sq, rook_moves, moves, state);
+        }
+    }
+
+    /// generates all moves for the queen on the current board
+    fn generate_queen_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        let piece_type: Piece = Piece::new(state.side_to_move, PieceKind::Queen);
+
+        for piece_sq in Squares::new(state.pieces(piece_type)) {
+            let bishop_moves = self.magic_generator.get_bishop_moves(piece_sq, state.occ[2]);
+            self.generate_attacking_moves(piece_sq, bishop_moves, moves, state);
+
+            let rook_moves = self.magic_generator.get_rook_moves(piece_sq, state.occ[2]);
+            self.generate_attacking_moves(piece_sq, rook_moves, moves, state);
+        }
+    }
+
+    /// generates all moves for the king on the current board
+    fn generate_king_moves(&mut self, moves: &mut MoveList, state: &mut ChessGameState) {
+        let piece_type: Piece = Piece::new(state.side_to_move, PieceKind::King);
+
+        for piece_sq in Squares::new(state.pieces(piece_type)) {
+            let king_moves: u64 = self.king_generator.mask[piece_sq.index()];
+            self.generate_attacking_moves(piece_sq, king_moves, moves, state);
+            self.generate_castle_moves(piece_sq, moves, state);
+        }
+    }
+
+    /// generates the castle moves for the king, a castle is only allowed if the side still has the right to castle,
+    /// all squares between the king and rook are empty and the king doesn't start, pass or end on an attacked square
+    fn generate_castle_moves(&mut self, king_sq: Square, moves: &mut MoveList, state: &mut ChessGameState) {
+        let side: Color = state.side_to_move;
+        // (king side right, queen side right, starting square of the king)
+        let (king_side, queen_side, start_sq) = if side == Color::White {
+            (WHITE_KING_SIDE, WHITE_QUEEN_SIDE, Square::E1)
+        } else {
+            (BLACK_KING_SIDE, BLACK_QUEEN_SIDE, Square::E8)
+        };
+        let rooks: u64 = state.pieces(Piece::new(side, PieceKind::Rook));
+
+        if king_sq != start_sq || state.castle_rights & (king_side | queen_side) == 0 {
+            return;
+        }
+
+        // we can't castle out of check
+        if self.is_square_attacked(king_sq, !side, state) {
+            return;
+        }
+
+        // the king is on the e-file, so every square up to the rooks is on the same rank
+        let sq = |delta: i32| king_sq.offset(delta).unwrap();
+
+        if state.castle_rights & king_side != 0
+            && rooks & sq(3).bit() != 0u64
+            && state.occ[2] & (sq(1).bit() | sq(2).bit()) == 0u64
+            && !self.is_square_attacked(sq(1), !side, state)
+            && !self.is_square_attacked(sq(2), !side, state)
+        {
+            moves.add_move(king_sq, sq(2), CASTLE);
+        }
+
+        if state.castle_rights & queen_side != 0
+            && rooks & sq(-4).bit() != 0u64
+            && state.occ[2] & (sq(-1).bit() | sq(-2).bit() | sq(-3).bit()) == 0u64
+            && !self.is_square_attacked(sq(-1), !side, state)
+            && !self.is_square_attacked(sq(-2), !side, state)
+        {
+            moves.add_move(king_sq, sq(-2), CASTLE);
+        }
+    }
+
+    /// generates the moves for pieces, adds a move non-capture move if there isn't a piece on the given square.
+    /// if there is a piece on the attacking square we can set capture=true
+    fn generate_attacking_moves(
+        &mut self, start_sq: Square, attacking_moves: u64, moves: &mut MoveList, state: &mut ChessGameState,
+    ) {
+        let occ_idx = state.get_capture_occ_idx();
+
+        for sq in Squares::new(attacking_moves) {
+            if state.occ[occ_idx as usize] & sq.bit() != 0u64 {
+                moves.add_move(start_sq, sq, CAPTURE);
+            } else if state.occ[2] & sq.bit() == 0u64 {
+                moves.add_move(start_sq, sq, QUIET);
+            }
+        }
+    }
+}
+
+/// returns the (bishops and queens, rooks and queens) of the side, the pieces that move diagonally and straight
+fn get_sliders(color: Color, state: &ChessGameState) -> (u64, u64) {
+    let queens: u64 = state.pieces(Piece::new(color, PieceKind::Queen));
+
+    return (
+        state.pieces(Piece::new(color, PieceKind::Bishop)) | queens,
+        state.pieces(Piece::new(color, PieceKind::Rook)) | queens,
+    );
+}
+
+/// returns the attack tables, the first call generates them
+pub fn get_attack_tables() -> &'static AttackTables {
+    return ATTACK_TABLES.get_or_init(|| {
+        let mut pawn_generator = Pawn { ..Default::default() };
+        let mut knight_generator = Knight { ..Default::default() };
+        let mut king_generator = King { ..Default::default() };
+        let mut magic_generator = MagicMoves { ..Default::default() };
+
+        pawn_generator.init();
+        knight_generator.init();
+        king_generator.init();
+        magic_generator.init();
+
+        return AttackTables {
+            pawn_generator,
+            knight_generator,
+            king_generator,
+            magic_generator,
+        };
+    });
+}
+
+impl Default for MoveGenerator {
+    fn default() -> Self {
+        let attack_tables: &'static AttackTables = get_attack_tables();
+
+        return Self {
+            pawn_generator: &attack_tables.pawn_generator,
+            knight_generator: &attack_tables.knight_generator,
+            king_generator: &attack_tables.king_generator,
+            magic_generator: &attack_tables.magic_generator,
+        };
+    }
+}
\ No newline at end of file
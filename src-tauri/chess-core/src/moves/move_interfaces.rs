@@ -0,0 +1,192 @@
+/*
+simple way to store move information
+ */
+
+use std::ops::Index;
+
+use serde::Serialize;
+
+use crate::board::state::ChessGameState;
+use crate::board::types::{Piece, PieceKind, Square};
+
+// flags are stored in the highest 4 bits of a move, the promotion piece is stored in the lowest 2 bits of the flags
+pub const QUIET: u16 = 0;
+pub const DOUBLE_PUSH: u16 = 1;
+pub const CASTLE: u16 = 2;
+pub const CAPTURE: u16 = 4;
+pub const EN_PASSANT: u16 = CAPTURE | 1;
+pub const PROMOTION: u16 = 8;
+
+/// the most moves a list can hold, no legal chess position has more than 218 moves
+pub const MAX_MOVES: usize = 256;
+
+/// a move packed in 16 bits: the start square(bits 0-5), end square(bits 6-11) and flags(bits 12-15).
+/// the moving piece isn't part of the move, it is the piece that stands on the start square before the move is made
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Move(u16);
+
+/// list of moves that lives on the stack, so generating moves doesn't allocate
+pub struct MoveList {
+    moves: [Move; MAX_MOVES],
+    len: usize,
+}
+
+/// the move with all fields separate and squares/pieces as indexes, the shape the front-end works with
+/// <br><br>promotion: piece index the pawn promotes to(-1 if none)
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct MoveData {
+    pub src: i32,
+    pub dest: i32,
+    pub piece_type: i32,
+    pub capture: bool,
+    pub castle: bool,
+    pub en_passant: bool,
+    pub promotion: i32,
+}
+
+pub trait AddMove {
+    fn add_move(&mut self, src: Square, dest: Square, flags: u16);
+    fn add_promotion_moves(&mut self, src: Square, dest: Square, capture: bool);
+    fn add_move_class(&mut self, chess_move: &Move);
+}
+
+impl Move {
+    /// empty move(a8 to a8), only used to fill up move lists
+    pub const NULL: Move = Move(0);
+
+    #[inline(always)]
+    pub fn new(src: Square, dest: Square, flags: u16) -> Move {
+        return Move(src.index() as u16 | (dest.index() as u16) << 6 | flags << 12);
+    }
+
+    #[inline(always)]
+    pub fn src(self) -> Square {
+        return Square::from_bits(self.0);
+    }
+
+    #[inline(always)]
+    pub fn dest(self) -> Square {
+        return Square::from_bits(self.0 >> 6);
+    }
+
+    #[inline(always)]
+    pub fn flags(self) -> u16 {
+        return self.0 >> 12;
+    }
+
+    pub fn is_capture(self) -> bool {
+        return self.flags() & CAPTURE != 0;
+    }
+
+    pub fn is_castle(self) -> bool {
+        return self.flags() == CASTLE;
+    }
+
+    pub fn is_en_passant(self) -> bool {
+        return self.flags() == EN_PASSANT;
+    }
+
+    pub fn is_double_push(self) -> bool {
+        return self.flags() == DOUBLE_PUSH;
+    }
+
+    /// the kind of piece the pawn promotes to, None if the move isn't a promotion
+    pub fn promotion(self) -> Option<PieceKind> {
+        if self.flags() & PROMOTION == 0 {
+            return None;
+        }
+
+        // knight, bishop, rook and queen are stored as 0-3
+        return Some(PieceKind::ALL[(self.flags() & 3) as usize + 1]);
+    }
+
+    /// converts the move to the shape the front-end works with, the state is the position before the move is made
+    pub fn to_move_data(self, state: &ChessGameState) -> MoveData {
+        let piece_type: i32 = state.piece_on(self.src()).map_or(-1, i32::from);
+        let promotion: i32 = self.promotion()
+            .map_or(-1, |kind| Piece::new(state.side_to_move, kind).into());
+
+        return MoveData {
+            src: self.src().into(),
+            dest: self.dest().into(),
+            piece_type,
+            capture: self.is_capture(),
+            castle: self.is_castle(),
+            en_passant: self.is_en_passant(),
+            promotion,
+        };
+    }
+}
+
+impl MoveList {
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Move> {
+        return self.moves[..self.len].iter();
+    }
+
+    pub fn as_slice(&self) -> &[Move] {
+        return &self.moves[..self.len];
+    }
+
+    /// the moves can be reordered(but not added or removed) through the slice
+    pub fn as_mut_slice(&mut self) -> &mut [Move] {
+        return &mut self.moves[..self.len];
+    }
+}
+
+impl Default for MoveList {
+    fn default() -> Self {
+        return Self {
+            moves: [Move::NULL; MAX_MOVES],
+            len: 0,
+        };
+    }
+}
+
+impl Index<usize> for MoveList {
+    type Output = Move;
+
+    fn index(&self, idx: usize) -> &Move {
+        return &self.as_slice()[idx];
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+impl AddMove for MoveList {
+    /// add a move to the moves(array)
+    #[inline(always)]
+    fn add_move(&mut self, src: Square, dest: Square, flags: u16) {
+        self.moves[self.len] = Move::new(src, dest, flags);
+        self.len += 1;
+    }
+
+    /// adds a move for every piece a pawn can promote to(queen, rook, bishop, knight)
+    fn add_promotion_moves(&mut self, src: Square, dest: Square, capture: bool) {
+        let capture_flag: u16 = if capture { CAPTURE } else { QUIET };
+
+        for kind in PieceKind::PROMOTIONS {
+            self.add_move(src, dest, PROMOTION | capture_flag | (kind.index() as u16 - 1));
+        }
+    }
+
+    /// adds a move that has already been initialized and set
+    fn add_move_class(&mut self, chess_move: &Move) {
+        self.moves[self.len] = *chess_move;
+        self.len += 1;
+    }
+}
@@ -0,0 +1,165 @@
+use crate::board::state::{
+    BLACK_KING_SIDE, BLACK_QUEEN_SIDE, ChessGameState, GameStateParser, SaveState, WHITE_KING_SIDE, WHITE_QUEEN_SIDE,
+};
+use crate::board::types::{Color, Piece, PieceKind, Square};
+use crate::board::zobrist::{get_zobrist_keys, ZobristKeys};
+use crate::moves::move_interfaces::Move;
+
+/// make a move on the chess board, it can be taken back with unmake_move
+/// <br>the move needs to be generated for the current position, the piece on its start square is the piece that moves
+pub fn make_move(chess_move: &Move, game_state: &mut ChessGameState) {
+    let side: Color = game_state.side_to_move;
+    let (src, dest) = (chess_move.src(), chess_move.dest());
+    let moved_piece: Piece = game_state.color_piece_on(side, src).unwrap();
+    let mut captured_piece: Option<Piece> = None;
+
+    // everything the move changes is XOR'ed out of the hash, and what it changes to is XOR'ed back in
+    let keys: &ZobristKeys = get_zobrist_keys();
+    let mut hash: u64 = game_state.hash
+        ^ keys.castle_rights[game_state.castle_rights as usize]
+        ^ keys.en_passant(game_state);
+
+//  clear captured piece
+    if chess_move.is_capture() {
+        captured_piece = game_state.color_piece_on(!side, dest);
+
+        if let Some(piece) = captured_piece {
+            game_state.bb[piece.index()] ^= dest.bit();
+            hash ^= keys.piece(piece, dest);
+        }
+    }
+
+    // only what the move itself changes can be undone without it being saved
+    game_state.saved_states.push(SaveState {
+        chess_move: *chess_move,
+        captured_piece,
+        castle_rights: game_state.castle_rights,
+        en_passant_sq: game_state.en_passant_sq,
+        halfmove_clock: game_state.halfmove_clock,
+        hash: game_state.hash,
+    });
+
+    // the pawn gets replaced by the piece it promotes to
+    let piece_on_dest: Piece = chess_move.promotion().map_or(moved_piece, |kind| Piece::new(side, kind));
+    game_state.bb[moved_piece.index()] ^= src.bit();
+    game_state.bb[piece_on_dest.index()] |= dest.bit();
+    hash ^= keys.piece(moved_piece, src) ^ keys.piece(piece_on_dest, dest);
+
+    // the captured pawn isn't on the dest square but right behind it
+    if chess_move.is_en_passant() {
+        let captured_sq: Square = get_en_passant_capture_sq(dest, side);
+
+        let captured_pawn: Piece = Piece::new(!side, PieceKind::Pawn);
+
+        game_state.bb[captured_pawn.index()] ^= captured_sq.bit();
+        hash ^= keys.piece(captured_pawn, captured_sq);
+    }
+
+    // a double pawn push allows the opponent to capture en passant on the square that has been skipped
+    game_state.en_passant_sq = None;
+    if chess_move.is_double_push() {
+        game_state.en_passant_sq = src.offset(side.forward());
+    }
+
+    // the king has already moved, so we only need to move the rook to the other side of the king
+    if chess_move.is_castle() {
+        let (rook_src, rook_dest) = get_castle_rook_squares(dest);
+
+        let rook: Piece = Piece::new(side, PieceKind::Rook);
+
+        game_state.bb[rook.index()] ^= rook_src.bit() | rook_dest.bit();
+        hash ^= keys.piece(rook, rook_src) ^ keys.piece(rook, rook_dest);
+    }
+
+    // moving the king/rook or capturing a rook on its starting square removes the castle rights for that side
+    game_state.castle_rights &= !(castle_rights_lost(src) | castle_rights_lost(dest));
+
+    // captures and pawn moves reset the halfmove clock, the fullmove number goes up after black has moved
+    if chess_move.is_capture() || moved_piece.kind == PieceKind::Pawn {
+        game_state.halfmove_clock = 0;
+    } else {
+        game_state.halfmove_clock += 1;
+    }
+    if side == Color::Black {
+        game_state.fullmove_number += 1;
+    }
+
+    game_state.side_to_move = !side;
+    game_state.update_occ();
+
+    // the en passant key depends on the pawns of the new side to move, so it can only be added after the move
+    game_state.hash = hash
+        ^ keys.black_to_move
+        ^ keys.castle_rights[game_state.castle_rights as usize]
+        ^ keys.en_passant(game_state);
+}
+
+/// takes back the latest made move(UNSAFE! doesn't check if a move has been made due to performance reasons)
+pub fn unmake_move(game_state: &mut ChessGameState) {
+    let saved_state: SaveState = game_state.saved_states.pop().unwrap();
+    let chess_move: Move = saved_state.chess_move;
+
+    game_state.side_to_move = !game_state.side_to_move;
+    let side: Color = game_state.side_to_move;
+    if side == Color::Black {
+        game_state.fullmove_number -= 1;
+    }
+
+    // a promoted piece turns back into the pawn
+    let (src, dest) = (chess_move.src(), chess_move.dest());
+    let piece_on_dest: Piece = game_state.color_piece_on(side, dest).unwrap();
+    let moved_piece: Piece = if chess_move.promotion().is_some() { Piece::new(side, PieceKind::Pawn) } else { piece_on_dest };
+    game_state.bb[piece_on_dest.index()] ^= dest.bit();
+    game_state.bb[moved_piece.index()] |= src.bit();
+
+    if let Some(piece) = saved_state.captured_piece {
+        game_state.bb[piece.index()] |= dest.bit();
+    }
+
+    if chess_move.is_en_passant() {
+        let captured_sq: Square = get_en_passant_capture_sq(dest, side);
+
+        game_state.bb[Piece::new(!side, PieceKind::Pawn).index()] |= captured_sq.bit();
+    }
+
+    if chess_move.is_castle() {
+        let (rook_src, rook_dest) = get_castle_rook_squares(dest);
+
+        game_state.bb[Piece::new(side, PieceKind::Rook).index()] ^= rook_src.bit() | rook_dest.bit();
+    }
+
+    game_state.castle_rights = saved_state.castle_rights;
+    game_state.en_passant_sq = saved_state.en_passant_sq;
+    game_state.halfmove_clock = saved_state.halfmove_clock;
+    game_state.hash = saved_state.hash;
+    game_state.update_occ();
+}
+
+/// returns the (start, end) square of the rook based on where the king castles to
+pub fn get_castle_rook_squares(king_dest: Square) -> (Square, Square) {
+    return match king_dest {
+        Square::G1 => (Square::H1, Square::F1),
+        Square::C1 => (Square::A1, Square::D1),
+        Square::G8 => (Square::H8, Square::F8),
+        _ => (Square::A8, Square::D8),
+    };
+}
+
+/// returns the square of the pawn that gets captured when the side moves en passant to the dest square
+pub fn get_en_passant_capture_sq(dest: Square, side: Color) -> Square {
+    // the en passant square is always on the 3rd or 6th rank, so the square behind it is on the board
+    return dest.offset(-side.forward()).unwrap();
+}
+
+/// returns the castle rights that are lost whenever a piece moves from, or gets captured on the square
+fn castle_rights_lost(sq: Square) -> u8 {
+    return match sq {
+        Square::E1 => WHITE_KING_SIDE | WHITE_QUEEN_SIDE,
+        Square::H1 => WHITE_KING_SIDE,
+        Square::A1 => WHITE_QUEEN_SIDE,
+        Square::E8 => BLACK_KING_SIDE | BLACK_QUEEN_SIDE,
+        Square::H8 => BLACK_KING_SIDE,
+        Square::A8 => BLACK_QUEEN_SIDE,
+        _ => 0,
+    };
+}
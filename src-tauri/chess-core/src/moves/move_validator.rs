@@ -0,0 +1,27 @@
+use crate::board::state::ChessGameState;
+use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+use crate::moves::move_interfaces::{AddMove, MoveList};
+use crate::moves::move_maker::{make_move, unmake_move};
+
+/// validates the moves so the user can't make illegal moves.
+/// makes every move to see if the king is safe, generate_legal_moves is faster but this is easier to trust
+pub fn validate_moves(
+    unvalidated_moves: MoveList,
+    move_generator: &mut MoveGenerator,
+    game_state: &mut ChessGameState
+) -> MoveList {
+    let mut valid_moves = MoveList::default();
+
+    for chess_move in &unvalidated_moves {
+        make_move(chess_move, game_state);
+
+        // the move is legal if our king isn't attacked after it, it's the opponent's turn now so we check the other king
+        if !move_generator.is_king_attacked(!game_state.side_to_move, game_state) {
+            valid_moves.add_move_class(chess_move);
+        }
+
+        unmake_move(game_state);
+    }
+
+    valid_moves
+}
\ No newline at end of file
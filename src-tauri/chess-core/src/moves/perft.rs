@@ -0,0 +1,60 @@
+use crate::board::state::ChessGameState;
+use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+use crate::moves::move_interfaces::{Move, MoveList};
+use crate::moves::move_maker::{make_move, unmake_move};
+
+/// counts all positions that can be reached after depth moves(performance test).
+/// the counts of a lot of positions are known, so this is the easiest way to find bugs in the move generator
+/// https://www.chessprogramming.org/Perft_Results
+pub fn perft(move_generator: &mut MoveGenerator, game_state: &mut ChessGameState, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves: MoveList = move_generator.generate_legal_moves(game_state);
+
+    // the moves on the last depth don't need to be made, we only need to know how many there are
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    let mut nodes: u64 = 0;
+    for chess_move in &moves {
+        make_move(chess_move, game_state);
+        nodes += perft(move_generator, game_state, depth - 1);
+        unmake_move(game_state);
+    }
+
+    return nodes;
+}
+
+/// perft split up per move of the current position, so a wrong count can be traced back to the move that causes it
+/// <br>returns (move name like "e2e4", nodes after that move)
+pub fn divide(move_generator: &mut MoveGenerator, game_state: &mut ChessGameState, depth: u32) -> Vec<(String, u64)> {
+    let mut divided: Vec<(String, u64)> = vec![];
+
+    if depth == 0 {
+        return divided;
+    }
+
+    let moves: MoveList = move_generator.generate_legal_moves(game_state);
+
+    for chess_move in &moves {
+        make_move(chess_move, game_state);
+        divided.push((move_name(chess_move), perft(move_generator, game_state, depth - 1)));
+        unmake_move(game_state);
+    }
+
+    return divided;
+}
+
+/// name of the move in long algebraic notation(start square, end square and promotion piece), for example "e7e8q"
+pub fn move_name(chess_move: &Move) -> String {
+    let mut name: String = chess_move.src().name() + &chess_move.dest().name();
+
+    if let Some(promotion) = chess_move.promotion() {
+        name.push(promotion.to_char());
+    }
+
+    return name;
+}
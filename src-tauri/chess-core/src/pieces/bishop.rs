@@ -2,6 +2,7 @@ pub mod bishop {
     use num::range_step;
 
     use crate::{get_bit, set_bit};
+    use crate::board::types::Square;
     use crate::pieces::piece_interfaces::SlidingPiece;
 
     pub struct Bishop {
@@ -10,15 +11,15 @@ pub mod bishop {
 
     impl SlidingPiece for Bishop {
         fn init(&mut self) {
-            for sq in 0..64 {
-                self.mask[sq] = self.get_mask(sq as i32);
+            for sq in Square::all() {
+                self.mask[sq.index()] = self.get_mask(sq);
             }
         }
 
-        fn get_mask(&self, sq: i32) -> u64 {
+        fn get_mask(&self, sq: Square) -> u64 {
             let mut attacks: u64 = 0u64;
-            let r: i32 = sq / 8;
-            let f: i32 = sq % 8;
+            let r: i32 = sq.rank();
+            let f: i32 = sq.file();
 
             for (rank, file) in (r+1..7).zip(f+1..7) {
                 set_bit!(&mut attacks, rank*8+file);
@@ -36,10 +37,10 @@ pub mod bishop {
             return attacks;
         }
 
-        fn get_full_move(&self, sq: i32, block: u64) -> u64 {
+        fn get_full_move(&self, sq: Square, block: u64) -> u64 {
             let mut attacks: u64 = 0u64;
-            let r: i32 = sq / 8;
-            let f: i32 = sq % 8;
+            let r: i32 = sq.rank();
+            let f: i32 = sq.file();
 
             for (rank, file) in (r+1..8).zip(f+1..8) {
                 set_bit!(&mut attacks, rank*8+file);
@@ -0,0 +1,42 @@
+use crate::board::bitboard::constants::{A_FILE, H_FILE};
+use crate::board::bitboard::math::set_bit_not_exists;
+use crate::board::types::Square;
+use crate::pieces::piece_interfaces::NonSlidingPiece;
+
+pub struct King {
+    pub mask: Vec<u64>
+}
+
+impl Default for King {
+    fn default() -> Self {
+        Self {
+            mask: vec![0u64; 64]
+        }
+    }
+}
+
+impl NonSlidingPiece for King{
+    fn init(&mut self) {
+        for sq in Square::all() {
+            self.mask[sq.index()] = self.get_mask(sq);
+        }
+    }
+
+    fn get_mask(&mut self, sq: Square) -> u64 {
+        let mut attacks: u64 = 0u64;
+
+        attacks = set_bit_not_exists(attacks, A_FILE, sq.offset(1));
+        attacks = set_bit_not_exists(attacks, A_FILE, sq.offset(-7));
+        attacks = set_bit_not_exists(attacks, A_FILE, sq.offset(9));
+
+        attacks = set_bit_not_exists(attacks, H_FILE, sq.offset(-1));
+        attacks = set_bit_not_exists(attacks, H_FILE, sq.offset(7));
+        attacks = set_bit_not_exists(attacks, H_FILE, sq.offset(-9));
+
+        // moving straight up or down can't wrap around the board, so no file is excluded
+        attacks = set_bit_not_exists(attacks, 0u64, sq.offset(-8));
+        attacks = set_bit_not_exists(attacks, 0u64, sq.offset(8));
+
+        return attacks;
+    }
+}
@@ -0,0 +1,41 @@
+use crate::board::bitboard::constants::{A_FILE, AB_FILE, GH_FILE, H_FILE};
+use crate::board::bitboard::math::set_bit_not_exists;
+use crate::board::types::Square;
+use crate::pieces::piece_interfaces::NonSlidingPiece;
+
+
+pub struct Knight {
+    pub mask: Vec<u64>
+}
+
+impl Default for Knight {
+    fn default() -> Self {
+        Self {
+            mask: vec![0u64; 64]
+        }
+    }
+}
+
+impl NonSlidingPiece for Knight {
+    fn init(&mut self) {
+        for sq in Square::all() {
+            self.mask[sq.index()] = self.get_mask(sq);
+        }
+    }
+
+    fn get_mask(&mut self, sq: Square) -> u64 {
+        let mut attacks: u64 = 0u64;
+
+        attacks = set_bit_not_exists(attacks, AB_FILE, sq.offset(-6));
+        attacks = set_bit_not_exists(attacks, GH_FILE, sq.offset(-10));
+        attacks = set_bit_not_exists(attacks, A_FILE, sq.offset(-15));
+        attacks = set_bit_not_exists(attacks, H_FILE, sq.offset(-17));
+
+        attacks = set_bit_not_exists(attacks, AB_FILE, sq.offset(10));
+        attacks = set_bit_not_exists(attacks, GH_FILE, sq.offset(6));
+        attacks = set_bit_not_exists(attacks, A_FILE, sq.offset(17));
+        attacks = set_bit_not_exists(attacks, H_FILE, sq.offset(15));
+
+        return attacks;
+    }
+}
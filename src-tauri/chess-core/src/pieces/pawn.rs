@@ -1,6 +1,7 @@
 pub mod pawn {
     use crate::board::bitboard::constants::{A_FILE, H_FILE};
     use crate::board::bitboard::math::set_bit_not_exists;
+    use crate::board::types::{Color, Square};
     use crate::pieces::piece_interfaces::MultiSideMovingPiece;
 
     // in pawn_mask will be all pre generated moves from get_mask
@@ -18,21 +19,22 @@ pub mod pawn {
 
     impl MultiSideMovingPiece for Pawn {
         fn init(&mut self) {
-            for sq in 0..64 {
-                self.mask[sq][0] = self.get_mask(sq as i32, true);
-                self.mask[sq][1] = self.get_mask(sq as i32, false);
+            for sq in Square::all() {
+                for color in Color::ALL {
+                    self.mask[sq.index()][color.index()] = self.get_mask(sq, color);
+                }
             }
         }
 
-        fn get_mask(&self, sq: i32, white_to_move: bool) -> u64{
+        fn get_mask(&self, sq: Square, color: Color) -> u64{
             let mut moves: u64 = 0u64;
 
-            if white_to_move {
-                moves = set_bit_not_exists(moves, A_FILE, sq-7);
-                moves = set_bit_not_exists(moves, H_FILE, sq-9);
+            if color == Color::White {
+                moves = set_bit_not_exists(moves, A_FILE, sq.offset(-7));
+                moves = set_bit_not_exists(moves, H_FILE, sq.offset(-9));
             }else {
-                moves = set_bit_not_exists(moves, H_FILE, sq+7);
-                moves = set_bit_not_exists(moves, A_FILE, sq+9);
+                moves = set_bit_not_exists(moves, H_FILE, sq.offset(7));
+                moves = set_bit_not_exists(moves, A_FILE, sq.offset(9));
             }
 
             moves
@@ -1,20 +1,22 @@
 // get_mask is used for pre_generating the moves for all of the pieces so that we can just
 // lookup the moves instead of generating them every single time
 
-/// this is for pieces that can if the moves are different if whiteToMove changed
+use crate::board::types::{Color, Square};
+
+/// this is for pieces that can if the moves are different if the side to move changed
 pub trait MultiSideMovingPiece {
     fn init(&mut self);
     /// generates a mask for MultiSideMovingPiece
-    fn get_mask(&self, sq: i32, white_to_move: bool) -> u64;
+    fn get_mask(&self, sq: Square, color: Color) -> u64;
 }
 
 /// this is for pieces that are not sliding(moves don't repeat along col-row) and don't change based
-/// on who's turn it is. So for this get_mask we will not need white_to_move
+/// on who's turn it is. So for this get_mask we will not need the color
 pub trait NonSlidingPiece {
     fn init(&mut self);
 
     /// generates all moves for non_sliding_pieces
-    fn get_mask(&mut self, sq: i32) -> u64;
+    fn get_mask(&mut self, sq: Square) -> u64;
 }
 
 /// Sliding pieces will need get_mask and get_full_move.
@@ -23,7 +25,7 @@ pub trait SlidingPiece {
     fn init(&mut self);
     /// gets the mask of a sliding piece, sliding piece masks don't go al the way until the edge of the board, it stops
     /// right before it
-    fn get_mask(&self, sq: i32) -> u64;
+    fn get_mask(&self, sq: Square) -> u64;
     /// moves also don't changed based on whose side it is
-    fn get_full_move(&self, sq: i32, block: u64) -> u64;
+    fn get_full_move(&self, sq: Square, block: u64) -> u64;
 }
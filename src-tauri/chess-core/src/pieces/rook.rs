@@ -2,6 +2,7 @@ pub mod rook {
     use num::range_step;
 
     use crate::{get_bit, set_bit};
+    use crate::board::types::Square;
     use crate::pieces::piece_interfaces::SlidingPiece;
 
     pub struct Rook {
@@ -10,23 +11,24 @@ pub mod rook {
 
     impl SlidingPiece for Rook {
         fn init(&mut self) {
-            for sq in 0..64 {
-                self.mask[sq] = self.get_mask(sq as i32);
+            for sq in Square::all() {
+                self.mask[sq.index()] = self.get_mask(sq);
             }
         }
 
-        fn get_mask(&self, sq: i32) -> u64 {
+        fn get_mask(&self, sq: Square) -> u64 {
             let mut moves: u64 = 0u64;
-            let r: i32 = sq / 8;
-            let f: i32 = sq % 8;
+            let r: i32 = sq.rank();
+            let f: i32 = sq.file();
 
-            for rank in r..7 {
+            // the square the rook is standing on isn't part of the mask, it would make the magic index collide
+            for rank in r+1..7 {
                 set_bit!(&mut moves, rank*8+f);
             }
             for rank in range_step(r-1, 0, -1) {
                 set_bit!(&mut moves, rank*8+f);
             }
-            for file in f..7 {
+            for file in f+1..7 {
                 set_bit!(&mut moves, r*8+file);
             }
             for file in range_step(f-1, 0, -1) {
@@ -36,14 +38,14 @@ pub mod rook {
             return moves;
         }
 
-        fn get_full_move(&self, sq: i32, block: u64) -> u64 {
+        fn get_full_move(&self, sq: Square, block: u64) -> u64 {
             /*
             we subtract/add 1 from rank/file due to us not wanting to select the
             square the piece is standing on.
              */
             let mut moves: u64 = 0u64;
-            let r: i32 = sq / 8;
-            let f: i32 = sq % 8;
+            let r: i32 = sq.rank();
+            let f: i32 = sq.file();
 
             for rank in r+1..8 {
                 set_bit!(&mut moves, rank*8+f);
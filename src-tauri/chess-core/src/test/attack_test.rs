@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod attack_tests {
+    use crate::board::types::Color;
+    use crate::moves::move_generator::{get_attack_tables, MoveCalculator, MoveGenerator};
+    use crate::test::{load, sq};
+
+    #[test]
+    fn test_square_attacked() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let state = load("4k3/8/8/3p4/8/8/8/R3K2B w - - 0 1");
+
+        // rook on a1 attacks along the first rank until the king, g1 is behind it
+        assert!(move_generator.is_square_attacked(sq("d1"), Color::White, &state));
+        assert!(!move_generator.is_square_attacked(sq("g1"), Color::White, &state));
+        // bishop on h1 is blocked by the pawn on d5
+        assert!(move_generator.is_square_attacked(sq("d5"), Color::White, &state));
+        assert!(!move_generator.is_square_attacked(sq("c6"), Color::White, &state));
+        // black pawn on d5 attacks c4 and e4
+        assert!(move_generator.is_square_attacked(sq("c4"), Color::Black, &state));
+        assert!(move_generator.is_square_attacked(sq("e4"), Color::Black, &state));
+        assert!(!move_generator.is_square_attacked(sq("d4"), Color::Black, &state));
+    }
+
+    #[test]
+    fn test_attack_maps() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let state = load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+
+        // every square on the 3rd rank is attacked by white in the starting position
+        assert_eq!(move_generator.get_attacks(Color::White, &state) & (0xFFu64 << 40), 0xFFu64 << 40);
+        assert_eq!(move_generator.get_attacks(Color::Black, &state) & (0xFFu64 << 16), 0xFFu64 << 16);
+        assert_eq!(move_generator.get_attacks(Color::White, &state) & (0xFFu64 << 24), 0u64);
+    }
+
+    #[test]
+    fn test_in_check() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+
+        assert!(move_generator.is_in_check(&load("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1")));
+        assert!(!move_generator.is_in_check(&load("4k3/8/8/8/8/8/4p3/4R1K1 b - - 0 1")));
+        assert!(move_generator.is_king_attacked(Color::White, &load("4k3/8/8/8/8/5n2/8/6K1 b - - 0 1")));
+    }
+
+    #[test]
+    fn test_shared_attack_tables() {
+        let move_generator = MoveGenerator { ..Default::default() };
+        let other_move_generator = MoveGenerator { ..Default::default() };
+
+        // both generators use the same tables instead of generating their own
+        assert!(std::ptr::eq(move_generator.magic_generator, other_move_generator.magic_generator));
+        assert!(std::ptr::eq(move_generator.pawn_generator, &get_attack_tables().pawn_generator));
+
+        // every thread can use the tables at the same time
+        let threads: Vec<std::thread::JoinHandle<bool>> = (0..4).map(|_| std::thread::spawn(|| {
+            let mut move_generator = MoveGenerator { ..Default::default() };
+            return move_generator.is_in_check(&load("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1"));
+        })).collect();
+
+        for thread in threads {
+            assert!(thread.join().unwrap());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod legal_move_tests {
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+    use crate::moves::move_interfaces::{Move, MoveList};
+    use crate::moves::move_maker::{make_move, unmake_move};
+    use crate::moves::move_validator::validate_moves;
+    use crate::test::{load, sq};
+
+    /// every move sorted, so 2 lists of moves can be compared
+    fn sorted_moves(moves: &MoveList) -> Vec<Move> {
+        let mut sorted: Vec<Move> = moves.as_slice().to_vec();
+        sorted.sort();
+
+        return sorted;
+    }
+
+    /// checks that the legal moves are the same as the validated moves, also for all positions after it up to depth
+    fn compare_with_validated(move_generator: &mut MoveGenerator, state: &mut ChessGameState, depth: u32) {
+        let legal_moves: MoveList = move_generator.generate_legal_moves(state);
+        let unvalidated_moves: MoveList = move_generator.generate_moves(state);
+        let validated_moves: MoveList = validate_moves(unvalidated_moves, move_generator, state);
+
+        assert_eq!(sorted_moves(&legal_moves), sorted_moves(&validated_moves), "{}", state.to_fen());
+
+        if depth == 0 {
+            return;
+        }
+
+        for chess_move in &legal_moves {
+            make_move(chess_move, state);
+            compare_with_validated(move_generator, state, depth - 1);
+            unmake_move(state);
+        }
+    }
+
+    #[test]
+    fn test_same_as_validated() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        ] {
+            let mut state = load(fen);
+            compare_with_validated(&mut move_generator, &mut state, 2);
+        }
+    }
+
+    #[test]
+    fn test_pinned_piece() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        // the knight on e2 is pinned by the rook on e8, a pinned knight can never move
+        let mut state = load("4r1k1/8/8/8/8/8/3QN3/4K3 w - - 0 1");
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+
+        assert!(!moves.iter().any(|m| m.src() == sq("e2")));
+        // the queen isn't pinned and the king may step aside
+        assert!(moves.iter().any(|m| m.src() == sq("d2") && m.dest() == sq("d8")));
+        assert!(moves.iter().any(|m| m.src() == sq("e1") && m.dest() == sq("d1")));
+    }
+
+    #[test]
+    fn test_check_evasions() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        // the rook on e8 gives check, only blocking on e2-e7, capturing it or moving the king is allowed
+        let mut state = load("4r1k1/8/8/8/8/8/R7/4K3 w - - 0 1");
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+
+        for chess_move in &moves {
+            let (src, dest) = (chess_move.src(), chess_move.dest());
+            assert!(src == sq("e1") || dest == sq("e2"), "{} {}", src, dest);
+        }
+        assert!(moves.iter().any(|m| m.src() == sq("a2") && m.dest() == sq("e2")));
+
+        // in double check only the king can move
+        let mut state = load("4r1k1/8/8/8/7b/8/R7/4K3 w - - 0 1");
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+
+        assert!(!moves.is_empty());
+        assert!(moves.iter().all(|m| m.src() == sq("e1")));
+    }
+
+    #[test]
+    fn test_en_passant_discovered_check() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        // capturing en passant would remove both pawns from the 5th rank and let the queen attack the king
+        let mut state = load("8/8/8/K2pP2q/8/8/8/7k w - d6 0 1");
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+
+        assert!(!moves.iter().any(|m| m.is_en_passant()));
+    }
+}
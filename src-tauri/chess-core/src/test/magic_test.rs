@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod magic_tests {
+    use crate::board::bitboard::constants::{BISHOP_MAGIC, BISHOP_RELEVANT_BITS, ROOK_MAGIC, ROOK_RELEVANT_BITS};
+    use crate::board::types::Square;
+    use crate::helpers::random::XorShiftRng;
+    use crate::moves::magic_moves::{MagicMoves, MagicMovesGenerator};
+
+    #[test]
+    fn test_magic_constants() {
+        let magic_moves = MagicMoves { ..Default::default() };
+
+        // every occupancy of the mask needs to give the same moves from the lookup table as when generating them
+        for sq in Square::all() {
+            let (bishop_bits, rook_bits) = (BISHOP_RELEVANT_BITS[sq.index()] as i32, ROOK_RELEVANT_BITS[sq.index()] as i32);
+
+            assert!(magic_moves.verify_magic_num(sq, BISHOP_MAGIC[sq.index()], bishop_bits, true), "bishop {}", sq);
+            assert!(magic_moves.verify_magic_num(sq, ROOK_MAGIC[sq.index()], rook_bits, false), "rook {}", sq);
+
+            // the tables only have the entries the relevant bits can index
+            assert_eq!(magic_moves.bishop_moves[sq.index()].len(), 1 << bishop_bits);
+            assert_eq!(magic_moves.rook_moves[sq.index()].len(), 1 << rook_bits);
+        }
+    }
+
+    #[test]
+    fn test_generate_magic_num() {
+        let mut magic_moves = MagicMoves { ..Default::default() };
+        let bits: i32 = ROOK_RELEVANT_BITS[0] as i32;
+        let magic: u64 = magic_moves.generate_magic_num(Square::A8, bits, false, &mut XorShiftRng::new(1));
+
+        assert_ne!(magic, 0u64);
+        assert!(magic_moves.verify_magic_num(Square::A8, magic, bits, false));
+        // a wrong magic number maps different moves on the same index
+        assert!(!magic_moves.verify_magic_num(Square::A8, 1u64, bits, false));
+
+        // the same seed always finds the same magic number
+        assert_eq!(magic_moves.generate_magic_num(Square::A8, bits, false, &mut XorShiftRng::new(1)), magic);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::board::bitboard::math::{get_ls1b, set_occ};
-use crate::helpers::random::generate_magic_number;
+use crate::helpers::random::{RandomNumberGenerator, XorShiftRng};
 
 #[test]
 fn test_ls1b() {
@@ -11,7 +11,18 @@ fn test_ls1b() {
 /// we are just going to test if the number != 0
 #[test]
 fn test_xorshift_random_num() {
-    assert_ne!(generate_magic_number(), 0)
+    assert_ne!(XorShiftRng::default().generate_magic_number(), 0)
+}
+
+#[test]
+fn test_xorshift_seed() {
+    let mut rng = XorShiftRng::new(42);
+    let numbers: Vec<u64> = (0..10).map(|_| rng.get_random_u64_number()).collect();
+
+    // the same seed gives the same numbers, another seed gives other numbers
+    let mut same_rng = XorShiftRng::new(42);
+    assert!(numbers.iter().all(|number| *number == same_rng.get_random_u64_number()));
+    assert_ne!(XorShiftRng::new(43).get_random_u64_number(), numbers[0]);
 }
 
 #[test]
@@ -0,0 +1,33 @@
+mod math_test;
+mod attack_test;
+mod legal_move_test;
+mod perft_test;
+mod move_maker_test;
+mod magic_test;
+mod types_test;
+mod move_list_test;
+mod zobrist_test;
+mod search_test;
+mod transposition_test;
+mod see_test;
+mod move_picker_test;
+
+#[cfg(test)]
+use crate::board::state::{ChessGameState, GameStateParser};
+#[cfg(test)]
+use crate::board::types::Square;
+
+/// creates a game state of the fen, the fen needs to be valid
+#[cfg(test)]
+pub(crate) fn load(fen: &str) -> ChessGameState {
+    let mut state = ChessGameState { ..Default::default() };
+    state.parse_fen(fen).unwrap();
+
+    return state;
+}
+
+/// the square with the name, like "e4"
+#[cfg(test)]
+pub(crate) fn sq(name: &str) -> Square {
+    return Square::from_name(name).unwrap();
+}
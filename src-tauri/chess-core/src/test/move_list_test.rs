@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod move_list_tests {
+    use crate::board::fen::FenError;
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::board::types::{PieceKind, Square};
+    use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+    use crate::moves::move_interfaces::{
+        AddMove, CAPTURE, CASTLE, DOUBLE_PUSH, EN_PASSANT, MAX_MOVES, Move, MoveData, MoveList, QUIET,
+    };
+    use crate::test::sq;
+
+    #[test]
+    fn test_move_encoding() {
+        assert_eq!(std::mem::size_of::<Move>(), 2);
+
+        let chess_move = Move::new(sq("e2"), sq("e4"), DOUBLE_PUSH);
+        assert_eq!((chess_move.src(), chess_move.dest()), (sq("e2"), sq("e4")));
+        assert!(chess_move.is_double_push() && !chess_move.is_capture() && chess_move.promotion().is_none());
+
+        assert!(Move::new(sq("e1"), sq("g1"), CASTLE).is_castle());
+        assert!(Move::new(sq("h1"), sq("h8"), CAPTURE).is_capture());
+        assert!(!Move::new(sq("h1"), sq("h8"), QUIET).is_capture());
+
+        let en_passant = Move::new(sq("e5"), sq("d6"), EN_PASSANT);
+        assert!(en_passant.is_en_passant() && en_passant.is_capture());
+    }
+
+    #[test]
+    fn test_promotion_moves() {
+        let mut moves = MoveList::default();
+        moves.add_promotion_moves(sq("b7"), sq("a8"), true);
+
+        assert_eq!(moves.len(), 4);
+        assert!(moves.iter().all(|m| m.is_capture() && !m.is_en_passant() && m.dest() == Square::A8));
+        let promotions: Vec<PieceKind> = moves.iter().filter_map(|m| m.promotion()).collect();
+        assert_eq!(promotions, PieceKind::PROMOTIONS.to_vec());
+    }
+
+    #[test]
+    fn test_move_list() {
+        let mut moves = MoveList::default();
+        assert!(moves.is_empty());
+
+        // the list has room for more moves than any position can have
+        for _ in 0..MAX_MOVES {
+            moves.add_move(Square::A1, Square::A8, QUIET);
+        }
+        assert_eq!(moves.len(), MAX_MOVES);
+        assert_eq!(moves[MAX_MOVES - 1], Move::new(Square::A1, Square::A8, QUIET));
+
+        // the position with the most legal moves that is known(218)
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen("R6R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - - 0 1").unwrap();
+        assert_eq!(MoveGenerator { ..Default::default() }.generate_legal_moves(&mut state).len(), 218);
+
+        // 24 queens have more moves than the list has room for, but so many pieces can't come from promotions
+        let mut state = ChessGameState { ..Default::default() };
+        let result = state.parse_fen("knQ1QQQQ/ppQ4Q/QQ5Q/Q2Q3Q/Q6Q/Q6Q/R6Q/BQQQQQQK w - - 0 1");
+        assert_eq!(result, Err(FenError::TooManyPromotedPieces { white: true }));
+    }
+
+    #[test]
+    fn test_move_data() {
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let moves: MoveList = MoveGenerator { ..Default::default() }.generate_legal_moves(&mut state);
+        let promotion: &Move = moves.iter()
+            .find(|m| m.dest() == sq("b8") && m.promotion() == Some(PieceKind::Knight))
+            .unwrap();
+
+        assert_eq!(promotion.to_move_data(&state), MoveData {
+            src: 8,
+            dest: 1,
+            piece_type: 0,
+            capture: true,
+            castle: false,
+            en_passant: false,
+            promotion: 1,
+        });
+    }
+}
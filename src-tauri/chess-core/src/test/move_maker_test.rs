@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod move_maker_tests {
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+    use crate::moves::move_interfaces::MoveList;
+    use crate::moves::move_maker::{make_move, unmake_move};
+
+    /// makes and takes back every legal move and checks the position is exactly the same as before
+    fn check_unmake(fen: &str) {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen(fen).unwrap();
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+        let (bb, occ) = (state.bb, state.occ);
+
+        for chess_move in &moves {
+            make_move(chess_move, &mut state);
+            assert_eq!(state.saved_states.len(), 1);
+            unmake_move(&mut state);
+
+            assert_eq!(state.to_fen(), fen, "{} -> {}", chess_move.src(), chess_move.dest());
+            assert_eq!((state.bb, state.occ), (bb, occ));
+            assert!(state.saved_states.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_unmake_castle() {
+        check_unmake("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 3 20");
+        check_unmake("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 3 20");
+    }
+
+    #[test]
+    fn test_unmake_en_passant() {
+        check_unmake("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2");
+        check_unmake("4k3/8/8/8/3Pp3/8/8/4K3 b - d3 0 2");
+    }
+
+    #[test]
+    fn test_unmake_promotion() {
+        // promotions with and without capturing the rook
+        check_unmake("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        check_unmake("4k3/8/8/8/8/8/p7/1R2K3 b - - 0 1");
+    }
+}
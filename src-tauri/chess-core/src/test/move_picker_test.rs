@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod move_picker_tests {
+    use crate::board::state::ChessGameState;
+    use crate::board::types::Square;
+    use crate::engine::move_picker::{History, history_bonus, KillerMoves, MAX_HISTORY, MovePicker};
+    use crate::engine::search::{Search, SearchResult, Searcher};
+    use crate::engine::time_manager::SearchLimits;
+    use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+    use crate::moves::move_interfaces::{Move, MoveList};
+    use crate::test::load;
+
+    // white can capture the queen and rook with the pawn, the knight can only capture a defended pawn
+    const FEN: &str = "4k3/8/p7/1pq1r3/3P4/2N5/8/7K w - - 0 1";
+
+    /// returns the legal move from src to dest
+    fn find(moves: &MoveList, src: &str, dest: &str) -> Move {
+        let (src, dest) = (Square::from_name(src).unwrap(), Square::from_name(dest).unwrap());
+        return *moves.iter().find(|m| m.src() == src && m.dest() == dest).unwrap();
+    }
+
+    /// hands out every move of the picker
+    fn pick_all(mut picker: MovePicker, state: &ChessGameState, history: &History) -> Vec<Move> {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut moves: Vec<Move> = vec![];
+
+        while let Some(chess_move) = picker.next(state, &mut move_generator, history) {
+            moves.push(chess_move);
+        }
+        return moves;
+    }
+
+    #[test]
+    fn test_stage_order() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state: ChessGameState = load(FEN);
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+
+        let hash_move: Move = find(&moves, "h1", "h2");
+        let killers: [Move; 2] = [find(&moves, "c3", "e4"), find(&moves, "h1", "g1")];
+        let mut history = History::default();
+        history.update(&state, find(&moves, "d4", "d5"), history_bonus(8));
+
+        let picked: Vec<Move> = pick_all(MovePicker::new(moves, hash_move, killers), &state, &history);
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+
+        // every move is handed out exactly once
+        assert_eq!(picked.len(), moves.len());
+        assert!(moves.iter().all(|m| picked.contains(m)));
+
+        // hash move, captures by victim, killers, quiet moves by history and the capture that loses the knight last
+        assert_eq!(picked[..6], [
+            hash_move,
+            find(&moves, "d4", "c5"),
+            find(&moves, "d4", "e5"),
+            killers[0],
+            killers[1],
+            find(&moves, "d4", "d5"),
+        ]);
+        assert_eq!(*picked.last().unwrap(), find(&moves, "c3", "b5"));
+    }
+
+    #[test]
+    fn test_captures_only() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state: ChessGameState = load(FEN);
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+        let good_captures: Vec<Move> = vec![find(&moves, "d4", "c5"), find(&moves, "d4", "e5")];
+
+        let picked: Vec<Move> = pick_all(MovePicker::captures(moves), &state, &History::default());
+        assert_eq!(picked, good_captures);
+
+        // a hash move or killer that isn't legal in the position is never handed out
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+        let illegal: Move = find(&moves, "c3", "e4");
+        let mut other_state: ChessGameState = load("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        let moves: MoveList = move_generator.generate_legal_moves(&mut other_state);
+        let picker = MovePicker::new(moves, illegal, [illegal; 2]);
+        let picked: Vec<Move> = pick_all(picker, &other_state, &History::default());
+        assert_eq!(picked.len(), 5);
+        assert!(!picked.contains(&illegal));
+    }
+
+    #[test]
+    fn test_killers_and_history() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state: ChessGameState = load(FEN);
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+        let (a, b, c) = (find(&moves, "c3", "e4"), find(&moves, "h1", "g1"), find(&moves, "d4", "d5"));
+
+        // the newest killer comes first, storing the same killer again doesn't forget the other one
+        let mut killers = KillerMoves::default();
+        killers.store(3, a);
+        killers.store(3, b);
+        killers.store(3, b);
+        assert_eq!(killers.get(3), [b, a]);
+        killers.store(3, c);
+        assert_eq!(killers.get(3), [c, b]);
+        assert_eq!(killers.get(4), [Move::NULL; 2]);
+        assert_eq!(killers.get(1000), [Move::NULL; 2]);
+
+        // the scores never leave the bounds, no matter how often a move scores beta
+        let mut history = History::default();
+        for _ in 0..1000 {
+            history.update(&state, a, history_bonus(20));
+            history.update(&state, b, -history_bonus(20));
+        }
+        assert!(history.get(&state, a) > 0 && history.get(&state, a) <= MAX_HISTORY);
+        assert!(history.get(&state, b) < 0 && history.get(&state, b) >= -MAX_HISTORY);
+
+        let score: i32 = history.get(&state, a);
+        history.age();
+        assert_eq!(history.get(&state, a), score / 2);
+        history.clear();
+        assert_eq!(history.get(&state, a), 0);
+    }
+
+    #[test]
+    fn test_search_with_ordering() {
+        let mut search = Search { ..Default::default() };
+        let mut state: ChessGameState = load(FEN);
+
+        // the pawn takes the queen instead of the rook
+        let limits = SearchLimits { depth: Some(4), ..Default::default() };
+        let result: SearchResult = search.search(&mut state, &limits);
+        let moves: MoveList = search.move_generator.generate_legal_moves(&mut state);
+        assert_eq!(result.best_move, Some(find(&moves, "d4", "c5")));
+    }
+}
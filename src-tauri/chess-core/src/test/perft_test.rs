@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod perft_tests {
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::moves::move_generator::MoveGenerator;
+    use crate::moves::perft::{divide, perft};
+
+    /// checks the node counts from depth 1 up to the length of expected_nodes
+    /// https://www.chessprogramming.org/Perft_Results
+    fn check_perft(fen: &str, expected_nodes: &[u64]) {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen(fen).unwrap();
+
+        for (depth, nodes) in expected_nodes.iter().enumerate() {
+            assert_eq!(perft(&mut move_generator, &mut state, depth as u32 + 1), *nodes, "depth {} of {}", depth + 1, fen);
+        }
+
+        // perft may not change the position
+        assert_eq!(state.to_fen(), fen);
+    }
+
+    #[test]
+    fn test_perft_start_position() {
+        check_perft("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", &[20, 400, 8902, 197281]);
+    }
+
+    #[test]
+    fn test_perft_kiwipete() {
+        check_perft("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1", &[48, 2039, 97862]);
+    }
+
+    #[test]
+    fn test_perft_position_3() {
+        check_perft("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", &[14, 191, 2812, 43238]);
+    }
+
+    #[test]
+    fn test_perft_position_4() {
+        check_perft("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1", &[6, 264, 9467]);
+        // same position with the colors flipped
+        check_perft("r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1", &[6, 264, 9467]);
+    }
+
+    #[test]
+    fn test_perft_position_5() {
+        check_perft("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8", &[44, 1486, 62379]);
+    }
+
+    #[test]
+    fn test_perft_position_6() {
+        check_perft("r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10", &[46, 2079, 89890]);
+    }
+
+    #[test]
+    fn test_divide() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+        let divided: Vec<(String, u64)> = divide(&mut move_generator, &mut state, 3);
+
+        assert_eq!(divided.len(), 20);
+        assert_eq!(divided.iter().map(|(_, nodes)| nodes).sum::<u64>(), 8902);
+        assert!(divided.contains(&("e2e4".to_string(), 600)));
+        assert!(divided.contains(&("g1f3".to_string(), 440)));
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod search_tests {
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::board::types::Square;
+    use crate::engine::evaluation::evaluate;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crate::engine::search::{MATE_SCORE, Search, SearchResult, Searcher};
+    use crate::engine::time_manager::{MOVE_OVERHEAD, SearchLimits, TimeManager};
+    use crate::moves::perft::move_name;
+    use crate::test::load;
+
+    /// searches the position and checks the search didn't change it
+    fn search(fen: &str, depth: u32) -> SearchResult {
+        let mut search = Search { ..Default::default() };
+        let mut state: ChessGameState = load(fen);
+        let result: SearchResult = search.search(&mut state, &SearchLimits { depth: Some(depth), ..Default::default() });
+
+        assert_eq!(state.to_fen(), fen);
+        return result;
+    }
+
+    #[test]
+    fn test_evaluation_is_symmetric() {
+        assert_eq!(evaluate(&load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")), 0);
+
+        // the same position with the colors flipped is just as good for the side to move
+        assert_eq!(
+            evaluate(&load("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1")),
+            evaluate(&load("r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1"))
+        );
+
+        // an extra queen is good for the side that has it
+        assert!(evaluate(&load("3qk3/8/8/8/8/8/8/4K3 b - - 0 1")) > 800);
+        assert!(evaluate(&load("3qk3/8/8/8/8/8/8/4K3 w - - 0 1")) < -800);
+    }
+
+    #[test]
+    fn test_finds_mate() {
+        let result: SearchResult = search("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", 2);
+        assert_eq!(move_name(&result.best_move.unwrap()), "a1a8");
+        assert_eq!(result.score, MATE_SCORE - 1);
+
+        // the rook cuts off the 7th rank first and mates on the next move
+        let result: SearchResult = search("7k/8/8/8/8/8/R7/1R4K1 w - - 0 1", 3);
+        assert_eq!(result.score, MATE_SCORE - 3);
+    }
+
+    #[test]
+    fn test_captures() {
+        // a free queen gets taken
+        let result: SearchResult = search("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1", 2);
+        assert_eq!(result.best_move.unwrap().dest(), Square::from_name("d5").unwrap());
+
+        // a protected pawn isn't worth the queen
+        let result: SearchResult = search("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1", 2);
+        assert_ne!(move_name(&result.best_move.unwrap()), "d1d5");
+    }
+
+    #[test]
+    fn test_quiescence() {
+        // the recapture is after the last move of the search, the quiescence search still sees it
+        let result: SearchResult = search("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1", 1);
+        assert_ne!(move_name(&result.best_move.unwrap()), "d1d5");
+
+        // a score in the middle of an exchange isn't trusted, after RxR RxR material is equal again
+        let result: SearchResult = search("3rk3/8/8/8/8/8/8/3RK3 w - - 0 1", 1);
+        assert!(result.score.abs() < 100, "{}", result.score);
+    }
+
+    #[test]
+    fn test_iterative_deepening() {
+        let result: SearchResult = search("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1", 3);
+        assert_eq!(result.depth, 3);
+        assert!(result.best_move.is_some());
+
+        // a mate is found on the first depth it can be seen at, searching deeper won't find a quicker one
+        let result: SearchResult = search("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", 6);
+        assert_eq!(result.depth, 1);
+
+        // the side that is getting mated keeps searching for a longer defence
+        let result: SearchResult = search("k7/8/1K6/8/8/8/8/7R b - - 0 1", 4);
+        assert_eq!((result.depth, result.score), (4, -MATE_SCORE + 2));
+    }
+
+    #[test]
+    fn test_move_time() {
+        let mut search = Search { ..Default::default() };
+        let mut state: ChessGameState = load("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+        let start: Instant = Instant::now();
+
+        let limits = SearchLimits { move_time: Some(Duration::from_millis(100)), ..Default::default() };
+        let result: SearchResult = search.search(&mut state, &limits);
+
+        assert!(start.elapsed() < Duration::from_millis(500));
+        assert!(result.best_move.is_some() && result.depth >= 1);
+    }
+
+    #[test]
+    fn test_stop_from_other_thread() {
+        let mut search = Search { ..Default::default() };
+        let mut state: ChessGameState = load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        let stop: Arc<AtomicBool> = search.stop_handle();
+
+        // without limits the search only ends when it's stopped
+        let stopper = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            stop.store(true, Ordering::Relaxed);
+        });
+        let result: SearchResult = search.search(&mut state, &SearchLimits { ..Default::default() });
+        stopper.join().unwrap();
+
+        // the move of the last finished iteration is played and the position is left as it was
+        assert!(result.best_move.is_some() && result.depth >= 1);
+        assert_eq!(state.to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    }
+
+    #[test]
+    fn test_time_allocation() {
+        let unlimited: TimeManager = TimeManager::new(&SearchLimits { depth: Some(5), ..Default::default() });
+        assert_eq!((unlimited.soft_limit, unlimited.hard_limit), (None, None));
+
+        // a move time is used exactly and the clock is ignored
+        let move_time: TimeManager = TimeManager::new(&SearchLimits {
+            move_time: Some(Duration::from_millis(500)),
+            time_left: Some(Duration::from_secs(60)),
+            ..Default::default()
+        });
+        assert_eq!(move_time.soft_limit, Some(Duration::from_millis(500)));
+        assert_eq!(move_time.hard_limit, Some(Duration::from_millis(500)));
+
+        // the clock is divided over the moves that still need to be made, plus the increment
+        let clock: TimeManager = TimeManager::new(&SearchLimits {
+            time_left: Some(Duration::from_secs(60) + MOVE_OVERHEAD),
+            increment: Duration::from_secs(1),
+            moves_to_go: Some(20),
+            ..Default::default()
+        });
+        assert_eq!(clock.soft_limit, Some(Duration::from_secs(4)));
+        assert_eq!(clock.hard_limit, Some(Duration::from_secs(12)));
+
+        // the search never takes more than what is left on the clock
+        let low_clock: TimeManager = TimeManager::new(&SearchLimits {
+            time_left: Some(Duration::from_millis(100) + MOVE_OVERHEAD),
+            increment: Duration::from_secs(2),
+            ..Default::default()
+        });
+        assert_eq!(low_clock.soft_limit, Some(Duration::from_millis(100)));
+        assert_eq!(low_clock.hard_limit, Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_game_ended() {
+        let stalemate: SearchResult = search("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1", 3);
+        assert!(stalemate.best_move.is_none());
+        assert_eq!(stalemate.score, 0);
+
+        let checkmate: SearchResult = search("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3", 3);
+        assert!(checkmate.best_move.is_none());
+        assert_eq!(checkmate.score, -MATE_SCORE);
+    }
+}
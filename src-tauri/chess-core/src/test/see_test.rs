@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod see_tests {
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::board::types::Square;
+    use crate::engine::see::{is_capture_safe, see};
+    use crate::game::{Game, GameHandler};
+    use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+    use crate::moves::move_interfaces::{Move, MoveList};
+
+    /// returns the SEE of the legal move from src to dest(the first one for promotions)
+    fn see_of(fen: &str, src: &str, dest: &str) -> i32 {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen(fen).unwrap();
+
+        let moves: MoveList = move_generator.generate_legal_moves(&mut state);
+        let (src, dest) = (Square::from_name(src).unwrap(), Square::from_name(dest).unwrap());
+        let chess_move: Move = *moves.iter().find(|m| m.src() == src && m.dest() == dest).unwrap();
+
+        return see(&mut move_generator, &state, chess_move);
+    }
+
+    #[test]
+    fn test_see() {
+        // https://www.chessprogramming.org/SEE_-_The_Swap_Algorithm
+        assert_eq!(see_of("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "e1", "e5"), 100);
+        // the queen behind the bishop and the queen behind the rook join the exchange(x-ray)
+        assert_eq!(see_of("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1", "d3", "e5"), -220);
+
+        // a defended pawn isn't worth the queen, a capture that isn't answered wins the piece
+        assert_eq!(see_of("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1", "d1", "d5"), -800);
+        assert_eq!(see_of("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1", "d1", "d5"), 900);
+
+        // moving to a square the opponent attacks loses the piece, unless it can be taken back
+        assert_eq!(see_of("4k3/8/8/8/8/2p5/8/1N2K3 w - - 0 1", "b1", "a3"), 0);
+        assert_eq!(see_of("4k3/8/8/8/8/2p5/8/1N5K w - - 0 1", "b1", "d2"), -320);
+        assert_eq!(see_of("4k3/8/8/8/8/2p5/8/1N2K3 w - - 0 1", "b1", "d2"), -220);
+    }
+
+    #[test]
+    fn test_see_special_moves() {
+        // the king can't take back on a square the other rook still defends
+        assert_eq!(see_of("8/8/8/3k4/3p4/8/3R4/3R2K1 w - - 0 1", "d2", "d4"), 100);
+        assert_eq!(see_of("8/8/8/3k4/3p4/8/3R4/6K1 w - - 0 1", "d2", "d4"), -400);
+
+        // the pawn captured en passant isn't on the end square
+        assert_eq!(see_of("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2", "e5", "d6"), 100);
+
+        // a promotion wins the difference between the queen and the pawn, unless the queen gets captured
+        assert_eq!(see_of("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7", "a8"), 800);
+        assert_eq!(see_of("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7", "a8"), -100);
+        assert_eq!(see_of("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7", "b8"), 1300);
+    }
+
+    #[test]
+    fn test_is_capture_safe() {
+        let mut game = Game { ..Default::default() };
+        game.set_position("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1").unwrap();
+
+        let moves: MoveList = game.get_legal_moves();
+        let capture: Move = *moves.iter().find(|m| m.is_capture()).unwrap();
+        assert!(!game.is_capture_safe(capture));
+
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        game.set_position("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1").unwrap();
+        let moves: MoveList = game.get_legal_moves();
+        let capture: Move = *moves.iter().find(|m| m.is_capture()).unwrap();
+        assert!(is_capture_safe(&mut move_generator, &game.game_state, capture));
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod transposition_tests {
+    use std::mem::size_of;
+
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::board::types::Square;
+    use crate::engine::search::{MATE_SCORE, Search, SearchResult, Searcher};
+    use crate::engine::time_manager::SearchLimits;
+    use crate::engine::transposition::{Bound, score_from_tt, score_to_tt, TranspositionTable, TTEntry};
+    use crate::game::{Game, GameHandler};
+    use crate::moves::move_interfaces::{Move, QUIET};
+
+    fn quiet_move(src: Square, dest: Square) -> Move {
+        return Move::new(src, dest, QUIET);
+    }
+
+    #[test]
+    fn test_table_size() {
+        // the amount of entries is the largest power of 2 that fits in the size
+        let table = TranspositionTable::new(1);
+        assert!(table.len().is_power_of_two());
+        assert!(table.len() * size_of::<TTEntry>() <= 1024 * 1024);
+        assert!(table.len() * 2 * size_of::<TTEntry>() > 1024 * 1024);
+
+        let mut table = TranspositionTable::new(0);
+        assert_eq!(table.len(), 1);
+        table.resize(2);
+        assert_eq!(table.len(), TranspositionTable::new(1).len() * 2);
+    }
+
+    #[test]
+    fn test_store_and_probe() {
+        let mut table = TranspositionTable::new(1);
+        let e2e4: Move = quiet_move(Square::from_name("e2").unwrap(), Square::from_name("e4").unwrap());
+
+        assert_eq!(table.probe(12345), None);
+        table.store(12345, 4, 35, Bound::Lower, e2e4, 3);
+
+        let entry: TTEntry = table.probe(12345).unwrap();
+        assert_eq!((entry.depth, entry.score, entry.bound, entry.best_move), (4, 35, Bound::Lower, e2e4));
+
+        // a position in the same entry doesn't give the entry of the other position
+        let other_key: u64 = 12345 + table.len() as u64;
+        assert_eq!(table.probe(other_key), None);
+
+        // storing the position again without a best move keeps the best move
+        table.store(12345, 5, 20, Bound::Upper, Move::NULL, 3);
+        assert_eq!(table.probe(12345).unwrap().best_move, e2e4);
+
+        table.clear();
+        assert_eq!(table.probe(12345), None);
+    }
+
+    #[test]
+    fn test_replacement() {
+        let mut table = TranspositionTable::new(1);
+        let (key, other_key) = (7u64, 7u64 + table.len() as u64);
+
+        // a deeper searched position of the same search isn't replaced by a shallower one
+        table.store(key, 6, 10, Bound::Exact, Move::NULL, 0);
+        table.store(other_key, 2, 20, Bound::Exact, Move::NULL, 0);
+        assert!(table.probe(key).is_some() && table.probe(other_key).is_none());
+
+        table.store(other_key, 6, 20, Bound::Exact, Move::NULL, 0);
+        assert!(table.probe(key).is_none() && table.probe(other_key).is_some());
+
+        // an entry of an earlier search is always replaced
+        table.new_search();
+        table.store(key, 1, 10, Bound::Exact, Move::NULL, 0);
+        assert!(table.probe(key).is_some());
+
+        // also when it's been so many searches that a u8 age would have wrapped around to the same age
+        table.store(key, 6, 10, Bound::Exact, Move::NULL, 0);
+        for _ in 0..256 {
+            table.new_search();
+        }
+        table.store(other_key, 1, 20, Bound::Exact, Move::NULL, 0);
+        assert!(table.probe(key).is_none() && table.probe(other_key).is_some());
+    }
+
+    #[test]
+    fn test_mate_scores() {
+        // a mate in 3 plies from a position 5 plies from the root is a mate in 3 from the position itself
+        assert_eq!(score_to_tt(MATE_SCORE - 8, 5), MATE_SCORE - 3);
+        assert_eq!(score_to_tt(-MATE_SCORE + 8, 5), -MATE_SCORE + 3);
+        // reached at ply 2 the same mate is 5 plies from the root
+        assert_eq!(score_from_tt(MATE_SCORE - 3, 2), MATE_SCORE - 5);
+        assert_eq!(score_from_tt(-MATE_SCORE + 3, 2), -MATE_SCORE + 5);
+
+        // normal scores are stored as they are
+        assert_eq!(score_to_tt(150, 7), 150);
+        assert_eq!(score_from_tt(-150, 7), -150);
+    }
+
+    #[test]
+    fn test_search_uses_table() {
+        let mut search = Search { transposition_table: TranspositionTable::new(4), ..Default::default() };
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+        let limits = SearchLimits { depth: Some(4), ..Default::default() };
+
+        // the second search finds the positions of the first one in the table, they still count as nodes
+        let first: SearchResult = search.search(&mut state, &limits);
+        let second: SearchResult = search.search(&mut state, &limits);
+        assert!(second.nodes > 0 && second.nodes < first.nodes / 2, "{} {}", first.nodes, second.nodes);
+        assert_eq!(first.best_move, second.best_move);
+
+        // the best move of the root is stored as an exact entry
+        let entry: TTEntry = search.transposition_table.probe(state.hash).unwrap();
+        assert_eq!((Some(entry.best_move), entry.bound), (second.best_move, Bound::Exact));
+
+        // mates are still found with the right distance
+        state.parse_fen("7k/8/8/8/8/8/R7/1R4K1 w - - 0 1").unwrap();
+        let mate: SearchResult = search.search(&mut state, &SearchLimits { depth: Some(5), ..Default::default() });
+        assert_eq!(mate.score, MATE_SCORE - 3);
+    }
+
+    #[test]
+    fn test_new_game_clears_table() {
+        let mut game = Game { ..Default::default() };
+        game.init_game();
+
+        game.find_best_move(&SearchLimits { depth: Some(2), ..Default::default() });
+        assert!(game.search.transposition_table.probe(game.game_state.hash).is_some());
+
+        game.new_game(None).unwrap();
+        assert!(game.search.transposition_table.probe(game.game_state.hash).is_none());
+    }
+}
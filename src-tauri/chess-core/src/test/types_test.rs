@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod types_tests {
+    use crate::board::types::{Color, Piece, PieceKind, Square, Squares};
+
+    #[test]
+    fn test_square_conversions() {
+        let e3: Square = Square::from_name("e3").unwrap();
+
+        assert_eq!(e3.index(), 44);
+        assert_eq!((e3.rank(), e3.file()), (5, 4));
+        assert_eq!(e3.name(), "e3");
+        assert_eq!(Square::new(44), Some(e3));
+        assert_eq!(Square::from_rank_file(5, 4), Some(e3));
+        assert_eq!(Square::from_ls1b(e3.bit() | Square::H1.bit()), e3);
+
+        // squares off the board can't be created
+        assert_eq!(Square::new(-1), None);
+        assert_eq!(Square::new(64), None);
+        assert_eq!(Square::from_rank_file(0, 8), None);
+        assert_eq!(Square::from_name("i1"), None);
+        assert_eq!(Square::from_name("a9"), None);
+        assert_eq!(Square::A8.offset(-8), None);
+        assert_eq!(Square::H1.offset(8), None);
+        assert_eq!(Square::E1.offset(-8), Square::from_name("e2"));
+    }
+
+    #[test]
+    fn test_square_iteration() {
+        let squares: Vec<Square> = Squares::new(Square::H1.bit() | Square::A8.bit() | Square::E1.bit()).collect();
+
+        assert_eq!(squares, vec![Square::A8, Square::E1, Square::H1]);
+        assert_eq!(Squares::new(0u64).count(), 0);
+        assert_eq!(Square::all().count(), 64);
+        assert!(Square::all().enumerate().all(|(idx, sq)| sq.index() == idx));
+    }
+
+    #[test]
+    fn test_pieces() {
+        // every piece has its own bitboard index in the order PNBRQKpnbrqk
+        for (idx, piece) in Piece::all().enumerate() {
+            assert_eq!(piece.index(), idx);
+            assert_eq!(Piece::from_index(idx as i32), Some(piece));
+            assert_eq!("PNBRQKpnbrqk".as_bytes()[idx] as char, piece.to_char());
+            assert_eq!(Piece::from_char(piece.to_char()), Some(piece));
+        }
+
+        assert_eq!(Piece::from_index(12), None);
+        assert_eq!(Piece::from_char('x'), None);
+        assert_eq!(Piece::from_char('q'), Some(Piece::new(Color::Black, PieceKind::Queen)));
+        assert_eq!(!Color::White, Color::Black);
+        assert_eq!(Color::from_white(false).index(), 1);
+    }
+}
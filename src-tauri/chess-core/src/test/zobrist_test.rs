@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod zobrist_tests {
+    use crate::board::state::{ChessGameState, GameStateParser};
+    use crate::board::types::Square;
+    use crate::board::zobrist::hash_position;
+    use crate::moves::move_generator::{MoveCalculator, MoveGenerator};
+    use crate::moves::move_interfaces::{Move, MoveList};
+    use crate::moves::move_maker::{make_move, unmake_move};
+    use crate::test::load;
+
+    /// plays every legal move up to the depth and checks the incremental hash against a from scratch hash,
+    /// both after making and after taking back every move
+    fn check_hashes(move_generator: &mut MoveGenerator, state: &mut ChessGameState, depth: u32) {
+        if depth == 0 {
+            return;
+        }
+
+        let hash: u64 = state.hash;
+        let moves: MoveList = move_generator.generate_legal_moves(state);
+
+        for chess_move in &moves {
+            make_move(chess_move, state);
+            assert_eq!(state.hash, hash_position(state), "{} after {}{}", state.to_fen(), chess_move.src(), chess_move.dest());
+
+            check_hashes(move_generator, state, depth - 1);
+
+            unmake_move(state);
+            assert_eq!(state.hash, hash);
+        }
+    }
+
+    /// finds the legal move from src to dest(the first one for promotions)
+    fn find_move(move_generator: &mut MoveGenerator, state: &mut ChessGameState, src: &str, dest: &str) -> Move {
+        let moves: MoveList = move_generator.generate_legal_moves(state);
+        let (src, dest) = (Square::from_name(src).unwrap(), Square::from_name(dest).unwrap());
+
+        return *moves.iter().find(|m| m.src() == src && m.dest() == dest).unwrap();
+    }
+
+    #[test]
+    fn test_incremental_hash() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+
+        // castling, en passant, promotions and captures of rooks that still have castle rights
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        ] {
+            let mut state: ChessGameState = load(fen);
+            check_hashes(&mut move_generator, &mut state, 3);
+            assert_eq!(state.to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn test_hash_identifies_position() {
+        let start_position: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let start_hash: u64 = load(start_position).hash;
+
+        // every part of the position changes the hash
+        assert_ne!(start_hash, load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").hash);
+        assert_ne!(start_hash, load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Kkq - 0 1").hash);
+        assert_ne!(start_hash, load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w Qkq - 0 1").hash);
+        assert_ne!(
+            load("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2").hash,
+            load("4k3/8/8/3pP3/8/8/8/4K3 w - - 0 2").hash
+        );
+
+        // the clocks aren't part of the position
+        assert_eq!(start_hash, load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 12 40").hash);
+
+        // an en passant square that no pawn can capture on doesn't change the position
+        assert_eq!(
+            load("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").hash,
+            load("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1").hash
+        );
+        // neither does one the pawn next to it can't capture on, taking both pawns off the rank uncovers the king
+        assert_eq!(
+            load("4k3/8/8/r2pP2K/8/8/8/8 w - d6 0 2").hash,
+            load("4k3/8/8/r2pP2K/8/8/8/8 w - - 0 2").hash
+        );
+    }
+
+    #[test]
+    fn test_hash_of_transposition() {
+        let mut move_generator = MoveGenerator { ..Default::default() };
+        let mut state: ChessGameState = load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        let start_hash: u64 = state.hash;
+
+        // the knights going out and back gives the start position again
+        for (src, dest) in [("g1", "f3"), ("g8", "f6"), ("f3", "g1"), ("f6", "g8")] {
+            let chess_move: Move = find_move(&mut move_generator, &mut state, src, dest);
+            make_move(&chess_move, &mut state);
+        }
+        assert_eq!(state.hash, start_hash);
+
+        // the same position reached with the moves in a different order
+        let mut other_state: ChessGameState = load("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        for (src, dest) in [("e2", "e3"), ("e7", "e6"), ("d2", "d3"), ("d7", "d6")] {
+            let chess_move: Move = find_move(&mut move_generator, &mut state, src, dest);
+            make_move(&chess_move, &mut state);
+        }
+        for (src, dest) in [("d2", "d3"), ("d7", "d6"), ("e2", "e3"), ("e7", "e6")] {
+            let chess_move: Move = find_move(&mut move_generator, &mut other_state, src, dest);
+            make_move(&chess_move, &mut other_state);
+        }
+        assert_eq!(state.hash, other_state.hash);
+        assert_ne!(state.hash, start_hash);
+    }
+}
@@ -1,2 +0,0 @@
-pub mod bitboard;
-pub mod state;
\ No newline at end of file
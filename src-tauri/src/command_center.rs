@@ -1,18 +1,77 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
 
-use crate::board::bitboard::math::get_ls1b;
-use crate::board::state::GameStateParser;
-use crate::game::{Game, GameHandler};
-use crate::moves::move_interfaces::Moves;
-use crate::moves::move_validator::king_alive_after_moves;
+use serde::Serialize;
+
+use chess_core::board::fen::FenError;
+use chess_core::board::state::GameStateParser;
+use chess_core::board::types::{Color, Piece, PieceKind, Square};
+use chess_core::engine::search::{DEFAULT_MOVE_TIME, Search, Searcher};
+use chess_core::engine::time_manager::SearchLimits;
+use chess_core::game::{Game, GameHandler, GameStatus};
+use chess_core::moves::move_generator::MoveCalculator;
+use chess_core::moves::move_interfaces::{Move, MoveData, MoveList};
+use chess_core::moves::move_maker::{get_castle_rook_squares, get_en_passant_capture_sq, unmake_move};
+use chess_core::moves::perft::divide;
 use crate::TauriStateHolder;
 
+/// longest the computer thinks when only a depth is given, a deep search could otherwise take minutes
+const MAX_DEPTH_MOVE_TIME: Duration = Duration::from_secs(10);
+
+/// pieces that moved/disappeared besides the moved piece itself, so the front-end can update its board
+#[derive(Serialize)]
+pub struct MadeMove {
+    /// [start square, end square] of the rook if the move was a castle
+    pub rook_move: Vec<i32>,
+    /// square of the pawn that was captured en passant(-1 if none)
+    pub captured_pawn_sq: i32,
+    /// piece the pawn has promoted to(-1 if none)
+    pub promotion_piece: i32,
+}
+
+/// move the computer has made, start_sq and dest_sq are -1 if the game has already ended
+#[derive(Serialize)]
+pub struct ComputerMove {
+    pub start_sq: i32,
+    pub dest_sq: i32,
+    pub made_move: MadeMove,
+}
+
+/// reason why a position couldn't be loaded, message is readable for the user
+#[derive(Serialize)]
+pub struct PositionError {
+    pub error: FenError,
+    pub message: String,
+}
+
+impl From<FenError> for PositionError {
+    fn from(error: FenError) -> Self {
+        let message: String = error.to_string();
+
+        return Self { error, message };
+    }
+}
+
+/// everything the front-end needs to know to undo the latest move on its board
+#[derive(Serialize)]
+pub struct UndoneMove {
+    /// [current square, original square, captured piece(-1 if none)]
+    pub squares: Vec<i32>,
+    /// [current square, original square] of the rook if the move was a castle
+    pub rook_move: Vec<i32>,
+    /// [square, piece] of the pawn that was captured en passant
+    pub restored_pawn: Vec<i32>,
+    /// piece that is back on the original square(differs from the piece on the board if the move was a promotion)
+    pub moved_piece: i32,
+}
+
 /// creates a tauri app based on a tauri builder(makes it possible to test it)
 /// In general you probably want to use tauri::Builder::default()
 pub fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R> {
-    let app_state = TauriStateHolder(
-        Mutex::new(Game { ..Default::default() }),
-    );
+    let game = Game { ..Default::default() };
+    let stop_search = game.search.stop_handle();
+    let app_state = TauriStateHolder(Mutex::new(game), stop_search, AtomicBool::new(false));
 
     // init the game so we can make moves, see the board, ect.
     app_state.0.lock().unwrap().init_game();
@@ -20,20 +79,41 @@ pub fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R
     builder
         .manage(app_state)
         .invoke_handler(tauri::generate_handler![
-            get_board, select_square, move_piece, undo_move, check_game_won, get_bitboard, get_occ, get_side
+            get_board, set_position, new_game, select_square, move_piece, computer_move, stop_search, undo_move, get_game_status, claim_draw, get_check_square, divide_moves, get_bitboard, get_occ, get_side
         ])
         // remove the string argument on your app
         .build(tauri::generate_context!())
         .expect("failed to build app")
 }
 
-/// if the front-end calls this command we will want to return the starting fen
+/// returns the fen of the current position, so the front-end can (re)load or share the board
 /// https://nl.wikipedia.org/wiki/Forsyth-Edwards_Notation
 #[tauri::command]
 pub fn get_board(state: tauri::State<TauriStateHolder>) -> String {
     let state_guard: MutexGuard<Game> = state.0.lock().unwrap();
 
-    return state_guard.default_fen.clone();
+    return state_guard.game_state.to_fen();
+}
+
+/// starts a new game from the given fen and returns the fen of the new board.
+/// the current game stays as it is if the fen can't be loaded
+#[tauri::command]
+pub fn set_position(fen: String, state: tauri::State<TauriStateHolder>) -> Result<String, PositionError> {
+    let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
+
+    state_guard.set_position(&fen)?;
+
+    return Ok(state_guard.game_state.to_fen());
+}
+
+/// throws away the current game(board, history and clocks) and starts a new one.
+/// starts from the given fen or the normal starting position if no fen is given, returns the fen of the new board
+#[tauri::command]
+pub fn new_game(fen: Option<String>, state: tauri::State<TauriStateHolder>) -> Result<String, PositionError> {
+    let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
+    state_guard.new_game(fen)?;
+
+    return Ok(state_guard.game_state.to_fen());
 }
 
 /// if a square is pressed on the front-end return all moves so we can display them on the front-end
@@ -41,14 +121,12 @@ pub fn get_board(state: tauri::State<TauriStateHolder>) -> String {
 pub fn select_square(square: i32, state: tauri::State<TauriStateHolder>) -> Vec<i32> {
     let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
     let mut vec: Vec<i32> = Vec::with_capacity(8);
-    let unvalidated_moves: Moves = state_guard.get_moves();
+    let legal_moves: MoveList = state_guard.get_legal_moves();
 
-    // validate all moves so that the king can easily be captured
-    let validated_moves: Moves = state_guard.validate_moves(unvalidated_moves);
-
-    for chess_move in validated_moves.moves {
-        if chess_move.src == square {
-            vec.push(chess_move.dest)
+    for chess_move in &legal_moves {
+        // promotions give multiple moves to the same square
+        if i32::from(chess_move.src()) == square && !vec.contains(&chess_move.dest().into()) {
+            vec.push(chess_move.dest().into())
         }
     }
 
@@ -56,69 +134,228 @@ pub fn select_square(square: i32, state: tauri::State<TauriStateHolder>) -> Vec<
 }
 
 /// moves a piece on the rust side(will also check if the move is valid).
-/// only call this for users not if you are using ai(algorithm) because this is really slow!
+/// only call this for users, an ai(algorithm) should make its moves on the game state directly
+/// returns None if the move isn't legal, the board stays the same then
+/// <br>promotion: piece a pawn promotes to('q', 'r', 'b' or 'n'), when not given the pawn will promote to a queen.
+/// any other letter is an error and no move is made
 #[tauri::command]
-pub fn move_piece(start_sq: i32, dest_sq: i32, state: tauri::State<TauriStateHolder>) {
+pub fn move_piece(
+    start_sq: i32, dest_sq: i32, promotion: Option<char>, state: tauri::State<TauriStateHolder>
+) -> Result<Option<MadeMove>, String> {
     let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
-    let moves: Moves = state_guard.get_moves();
 
-    // validate moves again, pretty slow but makes it someone can't make invalid moves by sending bad requests
-    let validated_moves: Moves = state_guard.validate_moves(moves);
+    let promotion_kind: PieceKind = match promotion {
+        None => PieceKind::Queen,
+        Some(letter) => match PieceKind::from_char(letter).filter(|kind| PieceKind::PROMOTIONS.contains(kind)) {
+            Some(kind) => kind,
+            None => return Err(format!("a pawn can't promote to '{}', choose q, r, b or n", letter)),
+        },
+    };
+
+    // only legal moves are generated, so someone can't make invalid moves by sending bad requests
+    let legal_moves: MoveList = state_guard.get_legal_moves();
+
+    for chess_move in legal_moves.iter().copied() {
+        if i32::from(chess_move.src()) == start_sq && i32::from(chess_move.dest()) == dest_sq
+            && chess_move.promotion().map_or(true, |kind| kind == promotion_kind) {
+            return Ok(Some(make_legal_move(&mut state_guard, chess_move)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// lets the computer search and make the best move for the side to move, so a user can play against it.
+/// the computer thinks for DEFAULT_MOVE_TIME if no depth or time is given, and at most MAX_DEPTH_MOVE_TIME if only a
+/// depth is given. the game isn't locked while the computer thinks, so the other commands keep working and
+/// stop_search can end the search. errors if the computer is already thinking, or if the position has changed before
+/// the computer was done
+/// <br>depth: amount of moves the computer looks ahead at most
+/// <br>move_time: milliseconds the computer thinks, the clock is ignored if it's given
+/// <br>time_left, increment: milliseconds on the clock of the computer and added after every move
+#[tauri::command]
+pub async fn computer_move(
+    depth: Option<u32>, move_time: Option<u64>, time_left: Option<u64>, increment: Option<u64>,
+    state: tauri::State<'_, TauriStateHolder>
+) -> Result<ComputerMove, String> {
+    if state.2.swap(true, Ordering::SeqCst) {
+        return Err("the computer is already thinking".to_string());
+    }
+    // a stop that was given before the computer was asked to move belongs to an earlier search
+    state.1.store(false, Ordering::SeqCst);
+
+    let mut limits = SearchLimits {
+        depth,
+        move_time: move_time.map(Duration::from_millis),
+        time_left: time_left.map(Duration::from_millis),
+        increment: Duration::from_millis(increment.unwrap_or(0)),
+        ..Default::default()
+    };
+
+    if limits.move_time.is_none() && limits.time_left.is_none() {
+        limits.move_time = Some(if limits.depth.is_none() { DEFAULT_MOVE_TIME } else { MAX_DEPTH_MOVE_TIME });
+    }
+
+    let computer_move: Result<ComputerMove, String> = search_computer_move(&state, limits).await;
+    state.2.store(false, Ordering::SeqCst);
+
+    return computer_move;
+}
+
+/// searches a copy of the position on a blocking thread, the game is only locked to copy the position and to make the
+/// move. the game keeps a search with the smallest table until the search comes back
+async fn search_computer_move(holder: &TauriStateHolder, limits: SearchLimits) -> Result<ComputerMove, String> {
+    let (mut game_state, mut search) = {
+        let mut state_guard: MutexGuard<Game> = holder.0.lock().unwrap();
 
-    for chess_move in validated_moves.moves {
-        if chess_move.src == start_sq && chess_move.dest == dest_sq {
-            state_guard.move_piece(chess_move);
-            break;
+        if !matches!(state_guard.get_game_status(), GameStatus::Ongoing | GameStatus::DrawClaimable(_)) {
+            return Ok(ComputerMove {
+                start_sq: -1,
+                dest_sq: -1,
+                made_move: MadeMove { rook_move: vec![], captured_pawn_sq: -1, promotion_piece: -1 },
+            });
         }
+
+        (state_guard.game_state.clone(), std::mem::replace(&mut state_guard.search, Search::new(0)))
+    };
+    let (searched_hash, searched_moves) = (game_state.hash, game_state.saved_states.len());
+
+    let (search, best_move) = tauri::async_runtime::spawn_blocking(move || {
+        let best_move: Option<Move> = search.search(&mut game_state, &limits).best_move;
+        (search, best_move)
+    }).await.map_err(|error| error.to_string())?;
+
+    let mut state_guard: MutexGuard<Game> = holder.0.lock().unwrap();
+    state_guard.search = search;
+
+    // the move was undone or a new game was started, the searched positions might belong to another game
+    if state_guard.game_state.hash != searched_hash || state_guard.game_state.saved_states.len() != searched_moves {
+        state_guard.search.transposition_table.clear();
+        state_guard.search.history.clear();
+        return Err("the position has changed while the computer was thinking".to_string());
+    }
+
+    // the game hasn't ended, so the search always finds a move
+    let chess_move: Move = best_move.ok_or("the computer found no move")?;
+
+    return Ok(ComputerMove {
+        start_sq: chess_move.src().into(),
+        dest_sq: chess_move.dest().into(),
+        made_move: make_legal_move(&mut state_guard, chess_move),
+    });
+}
+
+/// stops the search of computer_move, the computer makes the best move it has found so far.
+/// does nothing if the computer isn't thinking
+#[tauri::command]
+pub fn stop_search(state: tauri::State<TauriStateHolder>) {
+    state.1.store(true, Ordering::Relaxed);
+}
+
+/// makes a legal move and returns which other pieces the front-end needs to move/remove/replace
+fn make_legal_move(game: &mut Game, chess_move: Move) -> MadeMove {
+    let move_data: MoveData = chess_move.to_move_data(&game.game_state);
+    let mut made_move = MadeMove { rook_move: vec![], captured_pawn_sq: -1, promotion_piece: move_data.promotion };
+
+    if move_data.castle {
+        let (rook_src, rook_dest) = get_castle_rook_squares(chess_move.dest());
+        made_move.rook_move = vec![rook_src.into(), rook_dest.into()];
     }
+    if move_data.en_passant {
+        made_move.captured_pawn_sq = get_en_passant_capture_sq(chess_move.dest(), game.game_state.side_to_move).into();
+    }
+
+    game.move_piece(chess_move);
+    return made_move;
 }
 
 /// undo's the latest made chess move
 #[tauri::command]
-pub fn undo_move(state: tauri::State<TauriStateHolder>) -> Vec<i32> {
+pub fn undo_move(state: tauri::State<TauriStateHolder>) -> UndoneMove {
     let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
 
     // check if we even can undo the move
     if state_guard.game_state.saved_states.len() == 0 {
-        return vec![0i32; 2];
+        return UndoneMove { squares: vec![0i32; 2], rook_move: vec![], restored_pawn: vec![], moved_piece: -1 };
     }
 
     // get difference in occupancies
     // idea behind this is if we XOR the prev and curr occ[2] we will find a made chess_move
+    // the side that made the move isn't the side to move anymore
+    let mover: Color = !state_guard.game_state.side_to_move;
+    let opp_pawn: Piece = Piece::new(!mover, PieceKind::Pawn);
     let occ_idx: usize = state_guard.game_state.get_capture_occ_idx() as usize;
     let occ_copy = state_guard.game_state.occ[occ_idx];
-    state_guard.game_state.undo_state();
-    let occ_diff = occ_copy ^ state_guard.game_state.occ[occ_idx];
-    let start_square: i32 = get_ls1b(occ_diff & state_guard.game_state.occ[occ_idx]) as i32;
+    let king_dest: Square = state_guard.game_state.king_square(mover);
+    let opp_pawn_copy = state_guard.game_state.pieces(opp_pawn);
+    unmake_move(&mut state_guard.game_state);
+    state_guard.claimed_draw = None;
+    let mut occ_diff = occ_copy ^ state_guard.game_state.occ[occ_idx];
+
+    // a castle moves the king 2 squares, we take the rook out of the diff and send it back as a separate move
+    let mut rook_move: Vec<i32> = vec![];
+    let king_src: Square = state_guard.game_state.king_square(mover);
+    if (king_src.file() - king_dest.file()).abs() == 2 {
+        let (rook_src, rook_dest) = get_castle_rook_squares(king_dest);
+        occ_diff ^= rook_src.bit() | rook_dest.bit();
+        rook_move = vec![rook_dest.into(), rook_src.into()];
+    }
+
+    let start_square: Square = Square::from_ls1b(occ_diff & state_guard.game_state.occ[occ_idx]);
+    let squares: Vec<i32> = state_guard.move_made_in_diff(occ_diff, start_square);
 
-    return state_guard.move_made_in_diff(occ_diff, start_square);
+    // a pawn that came back somewhere else than the square we moved to was captured en passant
+    let mut restored_pawn: Vec<i32> = vec![];
+    let restored_pawns: u64 = state_guard.game_state.pieces(opp_pawn) & !opp_pawn_copy & !(1u64 << squares[0]);
+    if restored_pawns != 0u64 {
+        restored_pawn = vec![Square::from_ls1b(restored_pawns).into(), opp_pawn.into()];
+    }
+
+    let moved_piece: i32 = Square::new(squares[1])
+        .and_then(|sq| state_guard.game_state.piece_on(sq))
+        .map_or(-1, i32::from);
+
+    return UndoneMove { squares, rook_move, restored_pawn, moved_piece };
 }
 
-/// returns a code -1 if the game isn't over, 0 if it is a draw, 1 if the game is won
+/// returns if the game is still going on, or how it has ended(checkmate, stalemate or a draw)
 #[tauri::command]
-pub fn check_game_won(state: tauri::State<TauriStateHolder>) -> i32 {
+pub fn get_game_status(state: tauri::State<TauriStateHolder>) -> GameStatus {
     let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
-    let unvalidated_moves: Moves = state_guard.get_moves();
-
-    // validate all moves so that the king can easily be captured
-    let validated_moves: Moves = state_guard.validate_moves(unvalidated_moves);
-
-    // validate if the user can make any moves, if not check if the king is in check.
-    if validated_moves.moves.len() == 0 {
-        // change side because the person can't make any moves anyways.
-        state_guard.game_state.white_to_move = !state_guard.game_state.white_to_move;
-        let opp_moves = state_guard.get_moves();
-
-        return if king_alive_after_moves(&mut state_guard.game_state, opp_moves) {
-            0
-        } else if state_guard.game_state.white_to_move {
-            1
-        } else {
-            2
-        }
+
+    return state_guard.get_game_status();
+}
+
+/// ends the game in a draw if the side to move may claim one(threefold repetition or the 50-move rule),
+/// returns the status of the game after the claim
+#[tauri::command]
+pub fn claim_draw(state: tauri::State<TauriStateHolder>) -> GameStatus {
+    let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
+
+    return state_guard.claim_draw();
+}
+
+/// returns the square of the king of the side to move if it's in check(-1 if not), so the front-end can highlight it
+#[tauri::command]
+pub fn get_check_square(state: tauri::State<TauriStateHolder>) -> i32 {
+    let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
+    let game: &mut Game = &mut state_guard;
+
+    if !game.move_generator.is_in_check(&game.game_state) {
+        return -1;
     }
 
-    return -1;
+    return game.game_state.king_square(game.game_state.side_to_move).into();
+}
+
+/// counts the positions after depth moves for every move of the current position(perft divide).
+/// used to debug the move generator, returns (move name like "e2e4", nodes after that move)
+#[tauri::command]
+pub fn divide_moves(depth: u32, state: tauri::State<TauriStateHolder>) -> Vec<(String, u64)> {
+    let mut state_guard: MutexGuard<Game> = state.0.lock().unwrap();
+    let game: &mut Game = &mut state_guard;
+
+    return divide(&mut game.move_generator, &mut game.game_state, depth);
 }
 
 pub fn testing_active() -> bool {
@@ -129,19 +366,19 @@ pub fn testing_active() -> bool {
 pub fn get_bitboard(state: tauri::State<TauriStateHolder>) -> Vec<u64> {
     let state_guard: MutexGuard<Game> = state.0.lock().unwrap();
 
-    return if testing_active() { state_guard.game_state.bb.clone() } else { vec![0u64; 12] };
+    return if testing_active() { state_guard.game_state.bb.to_vec() } else { vec![0u64; 12] };
 }
 
 #[tauri::command]
 pub fn get_occ(state: tauri::State<TauriStateHolder>) -> Vec<u64> {
     let state_guard: MutexGuard<Game> = state.0.lock().unwrap();
 
-    return if testing_active() { state_guard.game_state.occ.clone() } else { vec![0u64; 3] };
+    return if testing_active() { state_guard.game_state.occ.to_vec() } else { vec![0u64; 3] };
 }
 
 #[tauri::command]
 pub fn get_side(state: tauri::State<TauriStateHolder>) -> bool {
     let state_guard: MutexGuard<Game> = state.0.lock().unwrap();
 
-    return if testing_active() { state_guard.game_state.white_to_move.clone() } else { true };
+    return if testing_active() { state_guard.game_state.side_to_move.is_white() } else { true };
 }
\ No newline at end of file
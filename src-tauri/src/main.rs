@@ -2,21 +2,17 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use crate::command_center::{create_app, testing_active};
-use crate::game::Game;
-use std::sync::Mutex;
-use crate::helpers::random::generate_magic_number;
+use chess_core::game::Game;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
 
-mod pieces;
-mod moves;
-mod helpers;
-mod board;
-mod game;
 mod test;
 mod command_center;
 
 
-// Makes it so tauri can handle the game state
-pub struct TauriStateHolder(pub Mutex<Game>);
+// Makes it so tauri can handle the game state, the stop flag of the search is kept outside the mutex so the search can
+// be stopped without locking the game. the last flag is true while the computer is thinking about a move
+pub struct TauriStateHolder(pub Mutex<Game>, pub Arc<AtomicBool>, pub AtomicBool);
 
 
 fn main() {
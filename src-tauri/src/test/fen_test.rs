@@ -3,7 +3,11 @@ use tauri::Manager;
 #[cfg(test)]
 mod fen_tests {
     use serde_json::Value::String;
+    use chess_core::board::fen::FenError;
+    use chess_core::board::state::{BLACK_QUEEN_SIDE, ChessGameState, GameStateParser, WHITE_KING_SIDE};
+    use chess_core::board::types::{Color, Piece, PieceKind, Square};
     use crate::command_center::create_app;
+    use crate::test::ipc_helper::{call_get_board, call_make_move, call_new_game, call_set_position, call_undo_move};
 
     use super::*;
 
@@ -23,7 +27,118 @@ mod fen_tests {
                 error: tauri::api::ipc::CallbackFn(1),
                 inner: serde_json::Value::Null,
             },
-            Ok(String("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR".parse().unwrap()))
+            Ok(String("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".parse().unwrap()))
         );
     }
+
+    #[test]
+    fn test_fen_after_move() {
+        let app = create_app(tauri::test::mock_builder());
+        let window = app.get_window("main").unwrap();
+
+        call_make_move(&window, r#"{"startSq": 52, "destSq": 36}"#);
+        assert_eq!(call_get_board(&window), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+
+        call_make_move(&window, r#"{"startSq": 6, "destSq": 21}"#);
+        assert_eq!(call_get_board(&window), "rnbqkb1r/pppppppp/5n2/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 1 2");
+
+        call_undo_move(&window);
+        call_undo_move(&window);
+        assert_eq!(call_get_board(&window), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    }
+
+    #[test]
+    fn test_set_position() {
+        let app = create_app(tauri::test::mock_builder());
+        let window = app.get_window("main").unwrap();
+        call_make_move(&window, r#"{"startSq": 52, "destSq": 36}"#);
+
+        let board = call_set_position(&window, r#"{"fen": "4k3/8/8/8/8/8/8/R3K3 w Q - 0 1"}"#);
+        assert_eq!(board, Ok(String("4k3/8/8/8/8/8/8/R3K3 w Q - 0 1".parse().unwrap())));
+
+        // the history of the previous game is gone
+        call_undo_move(&window);
+        assert_eq!(call_get_board(&window), "4k3/8/8/8/8/8/8/R3K3 w Q - 0 1");
+    }
+
+    #[test]
+    fn test_set_illegal_position() {
+        let app = create_app(tauri::test::mock_builder());
+        let window = app.get_window("main").unwrap();
+
+        // black is in check while it's white's turn
+        let error = call_set_position(&window, r#"{"fen": "4k3/8/8/8/8/8/8/4R1K1 w - - 0 1"}"#).unwrap_err();
+        assert_eq!(error["error"], "SideNotToMoveInCheck");
+
+        let error = call_set_position(&window, r#"{"fen": "4k3/8/8/8/8/8/8/4K3 w - e9"}"#).unwrap_err();
+        assert_eq!(error["error"]["InvalidEnPassant"], "e9");
+
+        // more queens than white has pawns to promote, the move list would overflow on the next status check
+        let fen: &str = r#"{"fen": "knQ1QQQQ/ppQ4Q/QQ5Q/Q2Q3Q/Q6Q/Q6Q/R6Q/BQQQQQQK w - - 0 1"}"#;
+        let error = call_set_position(&window, fen).unwrap_err();
+        assert_eq!(error["error"]["TooManyPromotedPieces"]["white"], true);
+
+        // the board doesn't change after a bad fen
+        assert_eq!(call_get_board(&window), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    }
+
+    #[test]
+    fn test_new_game() {
+        let app = create_app(tauri::test::mock_builder());
+        let window = app.get_window("main").unwrap();
+        call_make_move(&window, r#"{"startSq": 52, "destSq": 36}"#);
+        call_make_move(&window, r#"{"startSq": 12, "destSq": 28}"#);
+
+        let board = call_new_game(&window, "{}");
+        assert_eq!(board, Ok(String("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".parse().unwrap())));
+
+        // nothing is left to undo in a new game
+        call_undo_move(&window);
+        assert_eq!(call_get_board(&window), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+
+        let board = call_new_game(&window, r#"{"fen": "4k3/8/8/8/8/8/4P3/4K3 b - - 12 40"}"#);
+        assert_eq!(board, Ok(String("4k3/8/8/8/8/8/4P3/4K3 b - - 12 40".parse().unwrap())));
+    }
+
+    #[test]
+    fn test_parse_all_fields() {
+        let mut state = ChessGameState { ..Default::default() };
+        state.parse_fen("r3k2r/8/8/8/4Pp2/8/8/R3K2R b Kq e3 5 20").unwrap();
+
+        assert_eq!(state.side_to_move, Color::Black);
+        assert_eq!(state.castle_rights, WHITE_KING_SIDE | BLACK_QUEEN_SIDE);
+        assert_eq!(state.en_passant_sq, Square::from_name("e3"));
+        assert_eq!(state.halfmove_clock, 5);
+        assert_eq!(state.fullmove_number, 20);
+        assert_eq!(state.pieces(Piece::new(Color::White, PieceKind::Rook)).count_ones(), 2);
+    }
+
+    #[test]
+    fn test_invalid_fen() {
+        let parse = |fen: &str| ChessGameState { ..Default::default() }.parse_fen(fen);
+
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3"), Err(FenError::MissingField("side to move")));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K2 w - -"), Err(FenError::InvalidRankLength { rank: 1, squares: 7 }));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3/8 w - -"), Err(FenError::InvalidRankCount(9)));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4X3 w - -"), Err(FenError::InvalidPiece('X')));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/3KK3 w - -"), Err(FenError::TooManyKings { white: true }));
+        assert_eq!(parse("8/8/8/8/8/8/8/4K3 w - -"), Err(FenError::MissingKing { white: false }));
+        assert_eq!(parse("4k2P/8/8/8/8/8/8/4K3 w - -"), Err(FenError::PawnOnBackRank("h8".to_string())));
+        assert_eq!(parse("4k3/8/8/8/8/2P5/PPPPPPPP/4K3 w - -"), Err(FenError::TooManyPawns { white: true }));
+        assert_eq!(parse("qqqk4/ppppppp1/8/8/8/8/8/4K3 w - -"), Err(FenError::TooManyPromotedPieces { white: false }));
+        assert_eq!(parse("knQ1QQQQ/ppQ4Q/QQ5Q/Q2Q3Q/Q6Q/Q6Q/R6Q/BQQQQQQK w - - 0 1"),
+            Err(FenError::TooManyPromotedPieces { white: true }));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 x - -"), Err(FenError::InvalidSideToMove("x".to_string())));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w KK -"), Err(FenError::InvalidCastleRights("KK".to_string())));
+        // the king has left e1, and black has no rook on a8
+        assert_eq!(parse("r3k2r/8/8/8/8/8/4K3/R6R w KQkq -"), Err(FenError::ImpossibleCastleRight('K')));
+        assert_eq!(parse("4k2r/8/8/8/8/8/8/R3K2R w KQkq -"), Err(FenError::ImpossibleCastleRight('q')));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - e3"), Err(FenError::InvalidEnPassant("e3".to_string())));
+        // there is no black pawn on d5 that could have just moved past d6
+        assert_eq!(parse("4k3/8/8/4P3/8/8/8/4K3 w - d6 0 1"), Err(FenError::InvalidEnPassant("d6".to_string())));
+        // the pawn on d5 can't have come from d7 if a piece is standing there
+        assert_eq!(parse("4k3/3n4/8/3pP3/8/8/8/4K3 w - d6 0 1"), Err(FenError::InvalidEnPassant("d6".to_string())));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - -1 1"), Err(FenError::InvalidHalfmoveClock("-1".to_string())));
+        assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - 0 0"), Err(FenError::InvalidFullmoveNumber("0".to_string())));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod game_status_tests {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use serde_json::json;
+    use tauri::App;
+    use tauri::Manager;
+    use tauri::test::MockRuntime;
+    use tauri::Window;
+
+    use crate::command_center::create_app;
+    use crate::test::ipc_helper::{
+        call_claim_draw, call_computer_move, call_get_board, call_get_check_square, call_get_game_status, call_make_move, call_set_position,
+        call_stop_search, call_undo_move,
+    };
+
+    /// loads the fen and returns the status of the game
+    fn status_of(window: &Window<MockRuntime>, fen: &str) -> serde_json::Value {
+        call_set_position(window, &json!({ "fen": fen }).to_string()).expect("fen should be valid");
+        return call_get_game_status(window);
+    }
+
+    #[test]
+    fn test_ongoing() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        assert_eq!(call_get_game_status(&window), json!("Ongoing"));
+    }
+
+    #[test]
+    fn test_checkmate() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+        let fools_mate: &str = "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3";
+
+        assert_eq!(status_of(&window, fools_mate), json!({ "Checkmate": { "white_won": false } }));
+
+        // asking for the status may not change the game
+        assert_eq!(call_get_board(&window), fools_mate);
+    }
+
+    #[test]
+    fn test_stalemate() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        assert_eq!(status_of(&window, "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1"), json!("Stalemate"));
+        assert_eq!(call_get_board(&window), "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1");
+    }
+
+    #[test]
+    fn test_insufficient_material() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        let draw = json!({ "Draw": "InsufficientMaterial" });
+        assert_eq!(status_of(&window, "8/8/4k3/8/8/3NK3/8/8 w - - 0 1"), draw);
+        assert_eq!(status_of(&window, "8/8/4k1b1/8/8/3BK3/8/8 w - - 0 1"), draw);
+        // bishops on different colored squares can still checkmate
+        assert_eq!(status_of(&window, "8/8/4kb2/8/8/3BK3/8/8 w - - 0 1"), json!("Ongoing"));
+    }
+
+    /// moves both knights of both sides out and back again, which repeats the position once
+    fn shuffle_knights(window: &Window<MockRuntime>) {
+        for (start_sq, dest_sq) in [(62, 45), (6, 21), (45, 62), (21, 6)] {
+            call_make_move(window, &json!({ "startSq": start_sq, "destSq": dest_sq }).to_string());
+        }
+    }
+
+    #[test]
+    fn test_repetition() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // a draw can't be claimed while the game is ongoing
+        shuffle_knights(&window);
+        assert_eq!(call_get_game_status(&window), json!("Ongoing"));
+        assert_eq!(call_claim_draw(&window), json!("Ongoing"));
+
+        shuffle_knights(&window);
+        assert_eq!(call_get_game_status(&window), json!({ "DrawClaimable": "ThreefoldRepetition" }));
+
+        // the fivefold repetition ends the game without a claim
+        shuffle_knights(&window);
+        shuffle_knights(&window);
+        assert_eq!(call_get_game_status(&window), json!({ "Draw": "FivefoldRepetition" }));
+    }
+
+    #[test]
+    fn test_claim_draw() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        shuffle_knights(&window);
+        shuffle_knights(&window);
+        assert_eq!(call_claim_draw(&window), json!({ "Draw": "ThreefoldRepetition" }));
+        assert_eq!(call_get_game_status(&window), json!({ "Draw": "ThreefoldRepetition" }));
+
+        // taking back a move also takes back the claim
+        call_undo_move(&window);
+        assert_eq!(call_get_game_status(&window), json!("Ongoing"));
+    }
+
+    #[test]
+    fn test_move_rules() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        assert_eq!(status_of(&window, "4k3/8/8/8/8/8/8/R3K3 w - - 99 80"), json!("Ongoing"));
+        assert_eq!(status_of(&window, "4k3/8/8/8/8/8/8/R3K3 w - - 100 80"), json!({ "DrawClaimable": "FiftyMoveRule" }));
+        assert_eq!(call_claim_draw(&window), json!({ "Draw": "FiftyMoveRule" }));
+        assert_eq!(status_of(&window, "4k3/8/8/8/8/8/8/R3K3 w - - 150 80"), json!({ "Draw": "SeventyFiveMoveRule" }));
+
+        // checkmate on the last move still wins the game
+        assert_eq!(
+            status_of(&window, "R3k3/8/4K3/8/8/8/8/8 b - - 150 80"),
+            json!({ "Checkmate": { "white_won": true } })
+        );
+    }
+
+    #[test]
+    fn test_computer_move() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // the computer mates with the rook on the back rank
+        call_set_position(&window, r#"{"fen": "6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1"}"#).unwrap();
+        let computer_move = call_computer_move(&window, r#"{"depth": 2}"#);
+        assert_eq!((&computer_move["start_sq"], &computer_move["dest_sq"]), (&json!(56), &json!(0)));
+        assert_eq!(call_get_game_status(&window), json!({ "Checkmate": { "white_won": true } }));
+
+        // the game has ended, so there is no move left to make
+        let computer_move = call_computer_move(&window, "{}");
+        assert_eq!(computer_move["start_sq"], json!(-1));
+        assert_eq!(call_get_board(&window), "R5k1/5ppp/8/8/8/8/8/6K1 b - - 1 1");
+    }
+
+    #[test]
+    fn test_stop_search() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // the game isn't locked while the computer thinks, the other commands answer right away
+        let stop_window: Window<MockRuntime> = window.clone();
+        let stopper = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            let start: Instant = Instant::now();
+            assert_eq!(call_get_board(&stop_window), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+            assert!(start.elapsed() < Duration::from_millis(100), "{:?}", start.elapsed());
+            call_stop_search(&stop_window);
+        });
+
+        let start: Instant = Instant::now();
+        let computer_move = call_computer_move(&window, r#"{"depth": 64}"#);
+        stopper.join().unwrap();
+
+        // the computer still plays the best move it found before it was stopped
+        assert!(start.elapsed() < Duration::from_secs(5), "{:?}", start.elapsed());
+        assert_ne!(computer_move["start_sq"], json!(-1));
+    }
+
+    #[test]
+    fn test_check_square() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        assert_eq!(call_get_check_square(&window), -1);
+
+        call_set_position(&window, r#"{"fen": "4k3/8/8/8/8/8/8/4R1K1 b - - 0 1"}"#).unwrap();
+        assert_eq!(call_get_check_square(&window), 4);
+    }
+}
@@ -39,6 +39,10 @@ pub fn call_make_move(window: &Window<MockRuntime>, data: &str) {
     ).expect("move piece panicked!");
 }
 
+pub fn call_try_make_move(window: &Window<MockRuntime>, data: &str) -> Result<JsonValue, JsonValue> {
+    return ipc_request::<JsonValue>(window, "move_piece", data);
+}
+
 pub fn call_get_bitboard(window: &Window<MockRuntime>) -> Vec<u64> {
     return ipc_request::<Vec<u64>>(
         window, "get_bitboard", "{}",
@@ -51,6 +55,43 @@ pub fn call_get_bitboard(window: &Window<MockRuntime>) -> Vec<u64> {
 //     ).expect("move piece panicked!");
 // }
 
+pub fn call_get_board(window: &Window<MockRuntime>) -> String {
+    return ipc_request::<String>(
+        window, "get_board", "{}",
+    ).expect("get board panicked!");
+}
+
+pub fn call_set_position(window: &Window<MockRuntime>, data: &str) -> Result<JsonValue, JsonValue> {
+    return ipc_request::<JsonValue>(window, "set_position", data);
+}
+
+pub fn call_new_game(window: &Window<MockRuntime>, data: &str) -> Result<JsonValue, JsonValue> {
+    return ipc_request::<JsonValue>(window, "new_game", data);
+}
+
+pub fn call_get_game_status(window: &Window<MockRuntime>) -> JsonValue {
+    return ipc_request::<JsonValue>(window, "get_game_status", "{}").expect("get game status panicked!");
+}
+
+pub fn call_claim_draw(window: &Window<MockRuntime>) -> JsonValue {
+    return ipc_request::<JsonValue>(window, "claim_draw", "{}").expect("claim draw panicked!");
+}
+
+pub fn call_computer_move(window: &Window<MockRuntime>, data: &str) -> JsonValue {
+    return ipc_request::<JsonValue>(window, "computer_move", data).expect("computer move panicked!");
+}
+
+pub fn call_stop_search(window: &Window<MockRuntime>) {
+    ipc_request::<JsonValue>(window, "stop_search", "{}").expect("stop search panicked!");
+}
+
+pub fn call_get_check_square(window: &Window<MockRuntime>) -> i32 {
+    return ipc_request::<i32>(window, "get_check_square", "{}").expect("get check square panicked!");
+}
+
 pub fn call_undo_move(window: &Window<MockRuntime>) {
     ipc_request::<JsonValue>(window, "undo_move", "{}").expect("undo move ipc call panicked");
-}
\ No newline at end of file
+}
+pub fn call_divide_moves(window: &Window<MockRuntime>, data: &str) -> Vec<(String, u64)> {
+    return ipc_request::<Vec<(String, u64)>>(window, "divide_moves", data).expect("divide moves panicked!");
+}
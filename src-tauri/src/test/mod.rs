@@ -2,4 +2,5 @@
 mod fen_test;
 mod move_test;
 mod ipc_helper;
-mod math_test;
\ No newline at end of file
+mod game_test;
+mod perft_test;
\ No newline at end of file
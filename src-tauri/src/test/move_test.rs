@@ -1,11 +1,12 @@
+use serde_json::Value as JsonValue;
 use tauri::App;
 use tauri::Manager;
 use tauri::test::MockRuntime;
 use tauri::Window;
 
 use crate::command_center::create_app;
-use crate::get_bit;
-use crate::test::ipc_helper::{call_get_bitboard, call_get_moves, call_make_move, call_undo_move};
+use chess_core::get_bit;
+use crate::test::ipc_helper::{call_get_bitboard, call_get_moves, call_make_move, call_try_make_move, call_undo_move};
 
 // Note: The move test almost tests everything there is, due to the magic_generator also using a lot of math from:
 // random.rs, bitboard.rs, state.rs. so this makes us able to skip over a lot of testing.
@@ -32,6 +33,11 @@ mod move_gen_tests {
     fn test_make_move() {
         let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
         let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // a pawn can't move 3 squares, no move is made so there is nothing for the front-end to show
+        assert_eq!(call_try_make_move(&window, r#"{"startSq": 52, "destSq": 28}"#), Ok(JsonValue::Null));
+        assert_eq!(get_bit!(call_get_bitboard(&window)[0], 52), true);
+
         call_make_move(&window, r#"{"startSq": 52, "destSq": 36}"#);
         let bb: u64 = call_get_bitboard(&window)[0];
 
@@ -85,4 +91,90 @@ mod move_gen_tests {
         // check if the piece went back to it's original spot
         assert_ne!(get_bit!(bb, 36), true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_castle() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // clear the squares between the king and the rook on the king side
+        call_make_move(&window, r#"{"startSq": 52, "destSq": 36}"#);
+        call_make_move(&window, r#"{"startSq": 8, "destSq": 16}"#);
+        call_make_move(&window, r#"{"startSq": 62, "destSq": 45}"#);
+        call_make_move(&window, r#"{"startSq": 9, "destSq": 17}"#);
+        call_make_move(&window, r#"{"startSq": 61, "destSq": 52}"#);
+        call_make_move(&window, r#"{"startSq": 10, "destSq": 18}"#);
+
+        let king_squares: Vec<u64> = call_get_moves(&window, r#"{"square": 60}"#);
+        assert!(king_squares.contains(&62));
+
+        call_make_move(&window, r#"{"startSq": 60, "destSq": 62}"#);
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[5], 62), true);
+        assert_eq!(get_bit!(bb[3], 61), true);
+
+        // the rook needs to go back to its original square as well
+        call_undo_move(&window);
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[5], 60), true);
+        assert_eq!(get_bit!(bb[3], 63), true);
+    }
+
+    #[test]
+    fn test_en_passant() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        call_make_move(&window, r#"{"startSq": 52, "destSq": 36}"#);
+        call_make_move(&window, r#"{"startSq": 8, "destSq": 16}"#);
+        call_make_move(&window, r#"{"startSq": 36, "destSq": 28}"#);
+        // black pawn double pushes next to the white pawn
+        call_make_move(&window, r#"{"startSq": 11, "destSq": 27}"#);
+
+        let pawn_squares: Vec<u64> = call_get_moves(&window, r#"{"square": 28}"#);
+        assert!(pawn_squares.contains(&19));
+
+        call_make_move(&window, r#"{"startSq": 28, "destSq": 19}"#);
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[0], 19), true);
+        assert_eq!(get_bit!(bb[6], 27), false);
+
+        // the captured pawn needs to come back on its own square
+        call_undo_move(&window);
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[0], 28), true);
+        assert_eq!(get_bit!(bb[6], 27), true);
+    }
+
+    #[test]
+    fn test_promotion() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // walk the h-pawn over to the g-file and up to the 7th rank
+        call_make_move(&window, r#"{"startSq": 55, "destSq": 39}"#);
+        call_make_move(&window, r#"{"startSq": 14, "destSq": 30}"#);
+        call_make_move(&window, r#"{"startSq": 39, "destSq": 30}"#);
+        call_make_move(&window, r#"{"startSq": 6, "destSq": 21}"#);
+        call_make_move(&window, r#"{"startSq": 30, "destSq": 22}"#);
+        call_make_move(&window, r#"{"startSq": 21, "destSq": 36}"#);
+        call_make_move(&window, r#"{"startSq": 22, "destSq": 14}"#);
+        call_make_move(&window, r#"{"startSq": 8, "destSq": 16}"#);
+
+        // a pawn can't promote to a king, the move isn't made
+        assert!(call_try_make_move(&window, r#"{"startSq": 14, "destSq": 6, "promotion": "k"}"#).is_err());
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[0], 14), true);
+
+        // under promote to a knight
+        call_make_move(&window, r#"{"startSq": 14, "destSq": 6, "promotion": "n"}"#);
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[1], 6), true);
+        assert_eq!(get_bit!(bb[0], 6), false);
+
+        call_undo_move(&window);
+        let bb: Vec<u64> = call_get_bitboard(&window);
+        assert_eq!(get_bit!(bb[0], 14), true);
+        assert_eq!(get_bit!(bb[1], 6), false);
+    }
+}
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod perft_tests {
+    use tauri::App;
+    use tauri::Manager;
+    use tauri::test::MockRuntime;
+    use tauri::Window;
+
+    use crate::command_center::create_app;
+    use crate::test::ipc_helper::{call_divide_moves, call_set_position};
+
+    #[test]
+    fn test_divide_command() {
+        let app: App<MockRuntime> = create_app(tauri::test::mock_builder());
+        let window: Window<MockRuntime> = app.get_window("main").unwrap();
+
+        // promotions are named after the piece the pawn promotes to
+        call_set_position(&window, r#"{"fen": "8/4P1k1/8/8/8/8/8/4K3 w - - 0 1"}"#).unwrap();
+        let divided: Vec<(String, u64)> = call_divide_moves(&window, r#"{"depth": 1}"#);
+
+        for promotion in ["e7e8q", "e7e8r", "e7e8b", "e7e8n"] {
+            assert!(divided.contains(&(promotion.to_string(), 1)));
+        }
+    }
+}
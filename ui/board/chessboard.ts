@@ -14,6 +14,15 @@ export class chess_board {
         return Math.floor((c+r) % 2) == 0 ? "darkgrey" : "white"
     }
 
+    static clear(): void {
+        /*
+        removes all squares so a new board can be drawn
+         */
+        if(chess_board.html_chess_board === null) return;
+
+        chess_board.html_chess_board.replaceChildren();
+    }
+
     static create_empty_square(id: number): HTMLDivElement {
         /*
         Creates a chess square with no piece on it
@@ -63,6 +72,13 @@ export class chess_board {
         chess_board.html_chess_board.appendChild(img);
     }
 
+    static is_pawn(sq: number): boolean {
+        // pieces are images, so we can see which piece it is by its src
+        let square: Element = document.getElementsByClassName('square')[sq];
+
+        return square instanceof HTMLImageElement && /[wb]P\.png$/.test(square.src);
+    }
+
     static unset_movable_squares() {
         let squares = document.getElementsByClassName('movable')
 
@@ -71,6 +87,19 @@ export class chess_board {
         }
     }
 
+    // highlights the king that is in check, -1 removes the highlight
+    static set_check_square(sq: number): void {
+        let checked = document.getElementsByClassName('in_check');
+
+        while(checked.length != 0) {
+            checked[0].classList.remove('in_check');
+        }
+
+        if (sq !== -1) {
+            document.getElementsByClassName('square')[sq].classList.add('in_check');
+        }
+    }
+
     static set_squares_movable(moves: Array<number>): void {
         let squares: HTMLCollectionOf<Element> = document.getElementsByClassName('square')
 
@@ -109,19 +138,38 @@ export class chess_board {
         this.move_piece(start_sq, end_sq);
 
         if(start_sq_piece === -1) return;
+        this.restore_piece(start_sq, start_sq_piece);
+    }
+
+    /*
+    puts a piece back on the board that has been captured.
+    <br><br><br> params:
+    <br>sq: square the piece needs to be placed on.
+    <br>piece: index of the piece(same order as the bitboards in rust).
+    */
+    static restore_piece(sq: number, piece_idx: number): void {
         // piece int to string
-        let piece: string = "PNBRQKpnbrqk".charAt(start_sq_piece);
+        let piece: string = "PNBRQKpnbrqk".charAt(piece_idx);
         piece = (piece.toUpperCase() == piece ? "w" : "b") + piece.toUpperCase();
 
         let chess_board: HTMLElement | null = document.getElementById('chess_board');
         let squares: HTMLCollectionOf<Element> = document.getElementsByClassName('square');
         let new_piece_image: HTMLImageElement =
-            this.generate_piece_element(piece, start_sq);
+            this.generate_piece_element(piece, sq);
 
         if(chess_board === null) return;
-        console.log(squares[start_sq]);
-        chess_board.removeChild(squares[start_sq]);
-        chess_board.children[start_sq-1].insertAdjacentElement("afterend", new_piece_image);
+        chess_board.removeChild(squares[sq]);
+        chess_board.children[sq-1].insertAdjacentElement("afterend", new_piece_image);
+    }
+
+    // removes a piece from the board by replacing it with an empty square
+    static remove_piece(sq: number): void {
+        let chess_board: HTMLElement | null = document.getElementById('chess_board');
+        let squares: HTMLCollectionOf<Element> = document.getElementsByClassName('square');
+        let empty_div: HTMLDivElement = this.create_empty_square(sq);
 
+        if(chess_board === null) return;
+        chess_board.removeChild(squares[sq]);
+        chess_board.children[sq-1].insertAdjacentElement("afterend", empty_div);
     }
 }
\ No newline at end of file
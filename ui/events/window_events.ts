@@ -23,17 +23,42 @@ export class window_events {
         return Math.floor(square_height) * 8 + Math.floor(square_width)
     }
 
-    // if the key `z` has been pressed we want to undo a move
+    // if the key `z` has been pressed we want to undo a move, `f` loads a position from a fen, `n` starts a new game,
+    // `d` claims a draw(only works after a threefold repetition or 50 moves without a capture or pawn move),
+    // `c` lets the computer play the side to move(the user plays the other side) or turns the computer off again,
+    // `s` makes the computer move right away. undo and moving a piece are ignored while the computer is thinking
     init_keydown_event() {
         window.onkeydown = (event: KeyboardEvent) => {
             if(event.key.toLowerCase() == "z") {
-                Requests.undo_move();
+                if(!requests.computer_thinking) {
+                    Requests.undo_move();
+                }
+            } else if(event.key.toLowerCase() == "n") {
+                Requests.new_game_request();
+            } else if(event.key.toLowerCase() == "d") {
+                Requests.claim_draw_request();
+            } else if(event.key.toLowerCase() == "c") {
+                requests.computer_opponent = !requests.computer_opponent;
+
+                if(requests.computer_opponent && !requests.computer_thinking) {
+                    Requests.computer_move_request();
+                }
+            } else if(event.key.toLowerCase() == "s") {
+                Requests.stop_search_request();
+            } else if(event.key.toLowerCase() == "f") {
+                let fen: string | null = window.prompt("Load position from FEN:");
+
+                if(fen !== null) {
+                    Requests.set_position_request(fen).catch(() => {});
+                }
             }
         }
     }
 
     init_square_clicked_event() {
         window.onclick = (event: MouseEvent) => {
+            if(requests.computer_thinking) return;
+
             let square_clicked: number | undefined = this.square_clicked(event);
             let chess_squares = document.getElementsByClassName("square")
 
@@ -1,30 +1,32 @@
 export class Game {
-    /*
-    If code=-1 nothing happens, code=0 the game is drawn and needs to be reset, code=1 the game is won and needs to be
-    reset. (only reset after uses chooses to replay the game!)
-     */
-    static parse_win_code(code: number) {
+    // hides the game ended text when a new game starts
+    static hide_game_ended() {
         let game_ended_text_label: HTMLElement | null = document.getElementById("game-ended-text");
 
         if (game_ended_text_label === null) {
             return;
         }
 
-        switch (code) {
-            case 0:
-                game_ended_text_label.innerHTML = "Game has been drawn!";
-                game_ended_text_label.style.display = "block";
-                break;
-            case 1:
-                game_ended_text_label.innerHTML = "White Won!";
-                game_ended_text_label.style.display = "block";
-                break;
-            case 2:
-                game_ended_text_label.innerHTML = "Black Won!";
-                game_ended_text_label.style.display = "block";
-                break;
-            default:
-                break;
+        game_ended_text_label.style.display = "none";
+    }
+
+    // shows how the game has ended, nothing happens while the game is still ongoing
+    // (a draw that can be claimed with `d` doesn't end the game yet)
+    static parse_game_status(status: any) {
+        let game_ended_text_label: HTMLElement | null = document.getElementById("game-ended-text");
+
+        if (game_ended_text_label === null || status === "Ongoing" || status.DrawClaimable !== undefined) {
+            return;
+        }
+
+        if (status === "Stalemate") {
+            game_ended_text_label.innerHTML = "Game has been drawn by stalemate!";
+        } else if (status.Checkmate !== undefined) {
+            game_ended_text_label.innerHTML = status.Checkmate.white_won ? "White Won!" : "Black Won!";
+        } else if (status.Draw !== undefined) {
+            game_ended_text_label.innerHTML = "Game has been drawn! (" + status.Draw + ")";
         }
+
+        game_ended_text_label.style.display = "block";
     }
 }
\ No newline at end of file
@@ -5,6 +5,11 @@ import {chess_board} from "../board/chessboard";
 import {Game} from "../game";
 
 export class requests {
+    // if true the computer answers every move of the user
+    static computer_opponent: boolean = false;
+    // true while the computer is thinking, the user can't move or undo until the computer has moved
+    static computer_thinking: boolean = false;
+
     // gets the fen set in rust
     get_board_request = async (): Promise<void> => {
         return new Promise<void>((resolve, reject) => {
@@ -15,6 +20,35 @@ export class requests {
         });
     }
 
+    // starts a new game from a fen, the board is only redrawn if rust accepted the position
+    set_position_request = async (fen: string): Promise<void> => {
+        return new Promise<void>((resolve, reject) => {
+            invoke('set_position', {"fen": fen}).then((board_fen: unknown) => {
+                fen_helper.chess_fen = board_fen as string;
+                chess_board.clear();
+                new fen_helper().parse_fen();
+                this.check_square_request();
+                resolve();
+            }).catch((error: any) => {
+                alert(error.message);
+                reject(error);
+            });
+        });
+    }
+
+    // throws away the current game and draws the starting position
+    new_game_request = async (): Promise<void> => {
+        return new Promise<void>((resolve, reject) => {
+            invoke('new_game', {}).then((board_fen: unknown) => {
+                fen_helper.chess_fen = board_fen as string;
+                chess_board.clear();
+                new fen_helper().parse_fen();
+                Game.hide_game_ended();
+                resolve();
+            }).catch(reject);
+        });
+    }
+
     // gets all squares the user can move to if a square is clocked
     square_clicked_request = async(square_clicked: number): Promise<void> => {
         new Promise<void>((resolve, reject) => {
@@ -29,8 +63,8 @@ export class requests {
     // undo a chess move
     undo_move = async(): Promise<void> => {
         new Promise<void>((resolve, reject) => {
-            invoke("undo_move", {}).then((move: unknown) => {
-                let arr_move = move as Array<number>;
+            invoke("undo_move", {}).then((move: any) => {
+                let arr_move = move.squares as Array<number>;
                 // start is the current square the piece is currently on
                 // end square is where the piece needs to go to undo the move
                 let start: number = arr_move[0], dest: number = arr_move[1];
@@ -41,16 +75,54 @@ export class requests {
 
                 chess_board.unset_movable_squares();
                 chess_board.undo_move(start, dest, start_square_piece);
+
+                // a promoted piece needs to turn back into a pawn
+                if (move.moved_piece !== -1) {
+                    chess_board.restore_piece(dest, move.moved_piece);
+                }
+
+                // if the move was a castle we also need to move the rook back
+                let rook_move = move.rook_move as Array<number>;
+                if (rook_move.length == 2) {
+                    chess_board.move_piece(rook_move[0], rook_move[1]);
+                }
+
+                // a pawn captured en passant wasn't on the square we moved to, so it needs to be placed back separately
+                let restored_pawn = move.restored_pawn as Array<number>;
+                if (restored_pawn.length == 2) {
+                    chess_board.restore_piece(restored_pawn[0], restored_pawn[1]);
+                }
+                this.check_square_request();
                 resolve();
             }).catch(reject);
         })
     }
 
     // check if the user has won the game
-    check_game_won_request = async(): Promise<void> => {
+    game_status_request = async(): Promise<void> => {
+        new Promise<void>((resolve, reject) => {
+            invoke("get_game_status", {}).then((status: any) => {
+                Game.parse_game_status(status);
+                resolve();
+            }).catch(reject);
+        })
+    }
+
+    // ends the game in a draw if the side to move may claim one, otherwise the game just goes on
+    claim_draw_request = async(): Promise<void> => {
         new Promise<void>((resolve, reject) => {
-            invoke("check_game_won", {}).then((code: any) => {
-                Game.parse_win_code(code as number);
+            invoke("claim_draw", {}).then((status: any) => {
+                Game.parse_game_status(status);
+                resolve();
+            }).catch(reject);
+        })
+    }
+
+    // highlights the king of the side to move if it's in check
+    check_square_request = async(): Promise<void> => {
+        new Promise<void>((resolve, reject) => {
+            invoke("get_check_square", {}).then((sq: unknown) => {
+                chess_board.set_check_square(sq as number);
                 resolve();
             }).catch(reject);
         })
@@ -58,15 +130,83 @@ export class requests {
 
     // send to tauri that a piece has moved
     move_piece_request = async(start_sq: number, move_sq: number): Promise<void> => {
+        // a pawn reaching the last rank needs to know which piece it becomes
+        let promotion: string | null = null;
+        if (chess_board.is_pawn(start_sq) && (move_sq < 8 || move_sq > 55)) {
+            promotion = window.prompt("Promote to (q, r, b, n):", "q");
+        }
+
         new Promise<void>((resolve, reject) => {
-            invoke("move_piece", {"startSq": start_sq, "destSq": move_sq}).then(() => {
-                chess_board.unset_movable_squares();
-                chess_board.move_piece(start_sq, move_sq);
+            invoke("move_piece", {"startSq": start_sq, "destSq": move_sq, "promotion": promotion}).then((made_move: any) => {
+                // rust didn't make the move if it wasn't legal, so the computer doesn't need to answer it
+                if (made_move === null) {
+                    chess_board.unset_movable_squares();
+                    resolve();
+                    return;
+                }
+                this.show_made_move(start_sq, move_sq, made_move);
 
-                // after moving every single piece we will need to check if we won the game!
-                this.check_game_won_request();
+                if (requests.computer_opponent) {
+                    this.computer_move_request();
+                }
                 resolve();
-            }).catch(reject);
+            }).catch((error: any) => {
+                // rust doesn't know the piece the pawn should promote to
+                alert(error);
+                reject(error);
+            });
         })
     }
+
+    // lets the computer make a move for the side to move
+    computer_move_request = async(): Promise<void> => {
+        requests.computer_thinking = true;
+
+        new Promise<void>((resolve, reject) => {
+            invoke("computer_move", {}).then((computer_move: any) => {
+                requests.computer_thinking = false;
+
+                // the game has already ended if the computer couldn't find a move
+                if (computer_move.start_sq !== -1) {
+                    this.show_made_move(computer_move.start_sq, computer_move.dest_sq, computer_move.made_move);
+                }
+                resolve();
+            }).catch((error: any) => {
+                requests.computer_thinking = false;
+                reject(error);
+            });
+        })
+    }
+
+    // makes the computer play the best move it has found so far instead of thinking any longer
+    stop_search_request = async(): Promise<void> => {
+        new Promise<void>((resolve, reject) => {
+            invoke("stop_search", {}).then(() => resolve()).catch(reject);
+        })
+    }
+
+    // moves the pieces of a move rust has made on the board
+    show_made_move(start_sq: number, move_sq: number, made_move: any) {
+        chess_board.unset_movable_squares();
+        chess_board.move_piece(start_sq, move_sq);
+
+        if (made_move.promotion_piece !== -1) {
+            chess_board.restore_piece(move_sq, made_move.promotion_piece);
+        }
+
+        // rust sends the rook squares back if the king castled
+        let rook_move = made_move.rook_move as Array<number>;
+        if (rook_move.length == 2) {
+            chess_board.move_piece(rook_move[0], rook_move[1]);
+        }
+
+        // a pawn captured en passant isn't on the square we moved to
+        if (made_move.captured_pawn_sq !== -1) {
+            chess_board.remove_piece(made_move.captured_pawn_sq);
+        }
+
+        // after moving every single piece we will need to check if we won the game!
+        this.game_status_request();
+        this.check_square_request();
+    }
 }
\ No newline at end of file